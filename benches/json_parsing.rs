@@ -0,0 +1,74 @@
+//! Compare the time serde_json and simd-json take to parse a large browse response:
+//! `cargo bench --bench json_parsing --features simd-json`
+
+use std::time::{Duration, Instant};
+
+use musicbrainz_rs_nova::entity::release::Release;
+use musicbrainz_rs_nova::entity::BrowseResult;
+
+const RELEASES: usize = 5_000;
+const ITERATIONS: u32 = 20;
+
+/// A browse response with `count` fully populated releases.
+fn browse_fixture(count: usize) -> Vec<u8> {
+    let releases: Vec<_> = (0..count)
+        .map(|i| {
+            serde_json::json!({
+                "id": format!("18d4e9b4-9247-4b44-914a-{:012}", i),
+                "title": "Bleach",
+                "status": "Official",
+                "packaging": "Jewel Case",
+                "date": "1989-06-15",
+                "country": "US",
+                "barcode": "098787003420",
+                "disambiguation": "",
+                "text-representation": {"language": "eng", "script": "Latn"},
+                "release-events": [{"date": "1989-06-15", "area": {
+                    "id": "489ce91b-6658-3307-9877-795b68554c98",
+                    "name": "United States",
+                    "sort-name": "United States",
+                    "iso-3166-1-codes": ["US"]
+                }}],
+                "media": [{"position": 1, "format": "CD", "track-count": 13, "title": ""}]
+            })
+        })
+        .collect();
+
+    serde_json::to_vec(&serde_json::json!({
+        "release-count": count,
+        "release-offset": 0,
+        "releases": releases,
+    }))
+    .unwrap()
+}
+
+/// The mean time `parse` takes on a fresh copy of `fixture`.
+fn measure(fixture: &[u8], parse: impl Fn(Vec<u8>) -> BrowseResult<Release>) -> Duration {
+    let mut total = Duration::ZERO;
+    for _ in 0..ITERATIONS {
+        let body = fixture.to_vec();
+        let start = Instant::now();
+        let result = parse(body);
+        total += start.elapsed();
+        assert_eq!(result.entities.len(), RELEASES);
+    }
+    total / ITERATIONS
+}
+
+fn main() {
+    let fixture = browse_fixture(RELEASES);
+    println!(
+        "parsing {} releases ({} KiB), mean of {} runs",
+        RELEASES,
+        fixture.len() / 1024,
+        ITERATIONS
+    );
+
+    let serde_json = measure(&fixture, |body| serde_json::from_slice(&body).unwrap());
+    println!("serde_json: {:?}", serde_json);
+
+    let simd_json = measure(&fixture, |mut body| {
+        simd_json::serde::from_slice(&mut body).unwrap()
+    });
+    println!("simd-json:  {:?}", simd_json);
+}
@@ -1,6 +1,8 @@
+use crate::Error;
 use once_cell::sync::Lazy;
 use reqwest::header;
-use reqwest::Error;
+use reqwest::redirect;
+use reqwest::Proxy;
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::time::Duration;
@@ -11,8 +13,8 @@ use reqwest::blocking::{Client, RequestBuilder, Response};
 #[cfg(feature = "async")]
 use reqwest::{Client, RequestBuilder, Response};
 
-pub(crate) const BASE_URL: &str = "http://musicbrainz.org/ws/2";
-pub(crate) const BASE_COVERART_URL: &str = "http://coverartarchive.org";
+pub(crate) const BASE_URL: &str = "https://musicbrainz.org/ws/2";
+pub(crate) const BASE_COVERART_URL: &str = "https://coverartarchive.org";
 pub(crate) const FMT_JSON: &str = "?fmt=json";
 pub(crate) const PARAM_INC: &str = "&inc=";
 pub(crate) const PARAM_OFFSET: &str = "&offset=";
@@ -23,39 +25,105 @@ pub(crate) struct MusicBrainzClient(Arc<Mutex<Client>>);
 
 struct MusicBrainzRetries(Arc<Mutex<u32>>);
 
+static DEFAULT_LIMIT: Lazy<Mutex<Option<u8>>> = Lazy::new(|| Mutex::new(None));
+
+static INCLUDE_FALLBACK: Lazy<Mutex<bool>> = Lazy::new(|| Mutex::new(false));
+
+struct UrlSettings {
+    base_url: String,
+    https_only: bool,
+}
+
+static URL_SETTINGS: Lazy<Mutex<UrlSettings>> = Lazy::new(|| {
+    Mutex::new(UrlSettings {
+        base_url: BASE_URL.to_string(),
+        https_only: true,
+    })
+});
+
+/// The most redirects a request follows, as with the default reqwest redirect policy.
+const MAX_REDIRECTS: usize = 10;
+
+struct HttpClientSettings {
+    user_agent: &'static str,
+    proxy: Option<Proxy>,
+    #[cfg(feature = "dangerous")]
+    accept_invalid_certs: bool,
+}
+
 pub(crate) static HTTP_CLIENT: Lazy<MusicBrainzClient> = Lazy::new(init_http_client);
+static HTTP_CLIENT_SETTINGS: Lazy<Mutex<HttpClientSettings>> = Lazy::new(|| {
+    Mutex::new(HttpClientSettings {
+        user_agent: "musicbrainz_rs default",
+        proxy: None,
+        #[cfg(feature = "dangerous")]
+        accept_invalid_certs: false,
+    })
+});
 static HTTP_RETRIES: Lazy<MusicBrainzRetries> = Lazy::new(init_http_retries);
 
+pub(crate) static AUTH_TOKEN: Lazy<Mutex<Option<String>>> = Lazy::new(|| Mutex::new(None));
+#[cfg(feature = "submission")]
+pub(crate) static SUBMISSION_CLIENT: Lazy<Mutex<String>> = Lazy::new(|| {
+    Mutex::new(concat!("musicbrainz_rs_nova-", env!("CARGO_PKG_VERSION")).to_string())
+});
+
 impl MusicBrainzClient {
     pub(crate) fn get(&self, path: &str) -> RequestBuilder {
         let client_ref = Arc::clone(&HTTP_CLIENT.0);
         let client_lock = client_ref.lock().expect("Unable to get musicbrainz client");
         client_lock.get(path)
     }
+
+    pub(crate) fn head(&self, path: &str) -> RequestBuilder {
+        let client_ref = Arc::clone(&HTTP_CLIENT.0);
+        let client_lock = client_ref.lock().expect("Unable to get musicbrainz client");
+        client_lock.head(path)
+    }
+
+    #[cfg(feature = "submission")]
+    pub(crate) fn post(&self, path: &str) -> RequestBuilder {
+        let client_ref = Arc::clone(&HTTP_CLIENT.0);
+        let client_lock = client_ref.lock().expect("Unable to get musicbrainz client");
+        client_lock.post(path)
+    }
 }
 
 #[cfg(feature = "blocking")]
 impl MusicBrainzClient {
     pub(crate) fn send_with_retries(&self, request: RequestBuilder) -> Result<Response, Error> {
-        use std::thread;
-
-        let mut retries = *HTTP_RETRIES.0.lock().unwrap();
-        loop {
-            let request = request.try_clone().unwrap();
-            let response = request.send()?;
-            if response.status().as_u16() == HTTP_RATELIMIT_CODE && retries > 0 {
-                // It seems like the value in the response header is sometimes rounded-off to the
-                // lower number, which can be lower than when the server actually accepts the next
-                // request. So we add one to the received duration to account for this.
-                let headers = response.headers();
-                let retry_secs = headers.get("retry-after").unwrap().to_str().unwrap();
-                let duration = Duration::from_secs(retry_secs.parse::<u64>().unwrap() + 1);
-                thread::sleep(duration);
-                retries -= 1;
-            } else {
-                break Ok(response);
-            }
+        let retries = *HTTP_RETRIES.0.lock().unwrap();
+        send_with_retry_count(request, retries)
+    }
+
+    /// Send `request` a single time, even when it is rate limited: resending a submission
+    /// could apply it twice.
+    #[cfg(feature = "submission")]
+    pub(crate) fn send_once(&self, request: RequestBuilder) -> Result<Response, Error> {
+        send_with_retry_count(request, 0)
+    }
+}
+
+#[cfg(feature = "blocking")]
+fn send_with_retry_count(request: RequestBuilder, mut retries: u32) -> Result<Response, Error> {
+    use std::thread;
+
+    loop {
+        let request = request.try_clone().unwrap();
+        let response = request.send()?;
+        if response.status().as_u16() != HTTP_RATELIMIT_CODE {
+            break Ok(response);
+        }
+
+        let retry_after = retry_after(response.headers());
+        if retries == 0 {
+            break Err(Error::RateLimited { retry_after });
         }
+        // It seems like the value in the response header is sometimes rounded-off to the
+        // lower number, which can be lower than when the server actually accepts the next
+        // request. So we add one to the received duration to account for this.
+        thread::sleep(retry_after.unwrap_or_default() + Duration::from_secs(1));
+        retries -= 1;
     }
 }
 
@@ -65,42 +133,118 @@ impl MusicBrainzClient {
         &self,
         request: RequestBuilder,
     ) -> Result<Response, Error> {
-        use wasm_timer::Delay;
-        let mut retries = *HTTP_RETRIES.0.lock().unwrap();
+        let retries = *HTTP_RETRIES.0.lock().unwrap();
 
         #[cfg(feature = "rate_limit")]
         super::rate_limit::wait_for_ratelimit().await;
 
-        loop {
-            let request = request.try_clone().unwrap();
-            let response = request.send().await?;
-            if response.status().as_u16() == HTTP_RATELIMIT_CODE && retries > 0 {
-                let headers = response.headers();
-                let retry_secs = headers.get("retry-after").unwrap().to_str().unwrap();
-                let duration = Duration::from_secs(retry_secs.parse::<u64>().unwrap() + 1);
-                let _ = Delay::new(duration).await;
-                retries -= 1;
-            } else {
-                break Ok(response);
-            }
+        send_with_retry_count(request, retries).await
+    }
+
+    /// Send `request` a single time, even when it is rate limited: resending a submission
+    /// could apply it twice.
+    #[cfg(feature = "submission")]
+    pub(crate) async fn send_once(&self, request: RequestBuilder) -> Result<Response, Error> {
+        #[cfg(feature = "rate_limit")]
+        super::rate_limit::wait_for_ratelimit().await;
+
+        send_with_retry_count(request, 0).await
+    }
+}
+
+#[cfg(feature = "async")]
+async fn send_with_retry_count(
+    request: RequestBuilder,
+    mut retries: u32,
+) -> Result<Response, Error> {
+    use wasm_timer::Delay;
+
+    loop {
+        let request = request.try_clone().unwrap();
+        let response = request.send().await?;
+        if response.status().as_u16() != HTTP_RATELIMIT_CODE {
+            break Ok(response);
         }
+
+        let retry_after = retry_after(response.headers());
+        if retries == 0 {
+            break Err(Error::RateLimited { retry_after });
+        }
+        let _ = Delay::new(retry_after.unwrap_or_default() + Duration::from_secs(1)).await;
+        retries -= 1;
     }
 }
 
+/// Parse the `Retry-After` header of a rate limited response, in seconds.
+fn retry_after(headers: &header::HeaderMap) -> Option<Duration> {
+    headers
+        .get(header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
 fn init_http_client() -> MusicBrainzClient {
+    let settings = HTTP_CLIENT_SETTINGS
+        .lock()
+        .expect("Unable to get musicbrainz client settings");
+    let client = build_http_client(&settings).expect("Unable to set default user agent, the following values must be set in Cargo.toml : 'name', 'version', 'authors'");
+
+    MusicBrainzClient(Arc::new(Mutex::new(client)))
+}
+
+fn build_http_client(settings: &HttpClientSettings) -> Result<Client, Error> {
     let mut headers = header::HeaderMap::new();
     headers.insert(
         header::USER_AGENT,
-        header::HeaderValue::from_static("musicbrainz_rs default"),
+        header::HeaderValue::from_static(settings.user_agent),
     );
 
-    let client = Client::builder()
+    let mut builder = Client::builder()
         // see : https://github.com/hyperium/hyper/issues/2136
         .pool_max_idle_per_host(0)
         .default_headers(headers)
-        .build().expect("Unable to set default user agent, the following values must be set in Cargo.toml : 'name', 'version', 'authors'");
+        .redirect(redirect::Policy::custom(follow_redirect));
 
-    MusicBrainzClient(Arc::new(Mutex::new(client)))
+    // Without an explicit proxy, reqwest picks up the `HTTP_PROXY`/`HTTPS_PROXY` env vars.
+    if let Some(proxy) = &settings.proxy {
+        builder = builder.proxy(proxy.clone());
+    }
+
+    // Without a TLS backend, lib.rs stops the build with a clear error instead.
+    #[cfg(all(
+        feature = "dangerous",
+        any(feature = "default-tls", feature = "rustls")
+    ))]
+    if settings.accept_invalid_certs {
+        builder = builder.danger_accept_invalid_certs(true);
+    }
+
+    Ok(builder.build()?)
+}
+
+/// Follow redirects like reqwest does, except to plain HTTP urls while
+/// [`set_https_only`] is enabled.
+fn follow_redirect(attempt: redirect::Attempt) -> redirect::Action {
+    if attempt.previous().len() > MAX_REDIRECTS {
+        attempt.error("too many redirects")
+    } else if https_only() && attempt.url().scheme() != "https" {
+        let error = format!("refusing to follow redirect to {}", attempt.url());
+        attempt.error(error)
+    } else {
+        attempt.follow()
+    }
+}
+
+fn rebuild_http_client(settings: &HttpClientSettings) -> Result<(), Error> {
+    let client = build_http_client(settings)?;
+    let client_ref = Arc::clone(&HTTP_CLIENT.0);
+    let mut client_lock = client_ref.lock().expect("Unable to set musicbrainz client");
+    *client_lock = client;
+    Ok(())
 }
 
 fn init_http_retries() -> MusicBrainzRetries {
@@ -123,20 +267,205 @@ fn init_http_retries() -> MusicBrainzRetries {
 /// musicbrainz_rs_nova::config::set_user_agent("MyAwesomeTagger/1.2.0 ( http://myawesometagger.example.com )");
 /// ```
 pub fn set_user_agent(user_agent: &'static str) {
-    let client_ref = Arc::clone(&HTTP_CLIENT.0);
-    let mut client_lock = client_ref.lock().expect("Unable to set musicbrainz client");
+    let mut settings = HTTP_CLIENT_SETTINGS
+        .lock()
+        .expect("Unable to set musicbrainz client settings");
+    settings.user_agent = user_agent;
+    rebuild_http_client(&settings).expect("Unable to set user agent");
+}
 
-    let mut headers = header::HeaderMap::new();
+/// Build a User-Agent following the MusicBrainz conventions from the package name and version
+/// of the crate calling it, read at compile time, and a contact (url or email address), which
+/// must be a string literal: `name/version ( contact )`.
+///
+/// ## Example
+/// ```rust
+/// let user_agent = musicbrainz_rs_nova::user_agent!("me@example.com");
+/// assert_eq!(
+///     user_agent,
+///     concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"), " ( me@example.com )")
+/// );
+/// ```
+#[macro_export]
+macro_rules! user_agent {
+    ($contact:expr) => {
+        concat!(
+            env!("CARGO_PKG_NAME"),
+            "/",
+            env!("CARGO_PKG_VERSION"),
+            " ( ",
+            $contact,
+            " )"
+        )
+    };
+}
 
-    headers.insert(
-        header::USER_AGENT,
-        header::HeaderValue::from_static(user_agent),
-    );
+/// Set the User-Agent of every request to the one built by [`user_agent!`](crate::user_agent),
+/// so it never goes stale when the calling crate version is bumped.
+///
+/// ## Example
+/// ```rust
+/// musicbrainz_rs_nova::set_default_user_agent_from_cargo!("http://myawesometagger.example.com");
+/// ```
+#[macro_export]
+macro_rules! set_default_user_agent_from_cargo {
+    ($contact:expr) => {
+        $crate::config::set_user_agent($crate::user_agent!($contact))
+    };
+}
 
-    *client_lock = Client::builder()
-        .default_headers(headers)
-        .build()
-        .expect("Unable to set user agent");
+/// Send every request through the given HTTP/HTTPS proxy, optionally authenticating with
+/// a `(username, password)` pair.
+///
+/// When no proxy is set, the `HTTP_PROXY` and `HTTPS_PROXY` environment variables are used.
+///
+/// ## Example
+/// ```rust
+/// musicbrainz_rs_nova::config::set_proxy("http://proxy.example.com:3128", Some(("user", "hunter2")))
+///     .expect("Invalid proxy url");
+/// ```
+pub fn set_proxy(url: &str, auth: Option<(&str, &str)>) -> Result<(), Error> {
+    let mut proxy = Proxy::all(url)?;
+    if let Some((username, password)) = auth {
+        proxy = proxy.basic_auth(username, password);
+    }
+
+    let mut settings = HTTP_CLIENT_SETTINGS
+        .lock()
+        .expect("Unable to set musicbrainz client settings");
+    settings.proxy = Some(proxy);
+    rebuild_http_client(&settings)
+}
+
+/// Accept any TLS certificate, e.g. the self-signed certificate of a local MusicBrainz mirror
+/// set with [`set_base_url`]. Disabled by default.
+///
+/// **Never enable this against a server you don't control**: an invalid or expired
+/// certificate, or one issued for another host, is trusted too, so anyone on the network path
+/// can impersonate the server. Only meant for development and testing.
+///
+/// Requires the `dangerous` feature, and a TLS backend.
+///
+/// ## Example
+/// ```rust
+/// use musicbrainz_rs_nova::config;
+///
+/// config::set_base_url("https://localhost:5000/ws/2").unwrap();
+/// config::set_accept_invalid_certs(true);
+/// ```
+#[cfg(feature = "dangerous")]
+pub fn set_accept_invalid_certs(accept: bool) {
+    // The client is built from the settings on first use: build it before locking them.
+    Lazy::force(&HTTP_CLIENT);
+    let mut settings = HTTP_CLIENT_SETTINGS
+        .lock()
+        .expect("Unable to set musicbrainz client settings");
+    settings.accept_invalid_certs = accept;
+    rebuild_http_client(&settings).expect("Unable to accept invalid certificates");
+}
+
+/// Set the number of entities returned by search and browse queries that don't set their own
+/// `limit`. MusicBrainz accepts a limit between 1 and 100, and returns 25 entities by default.
+///
+/// ## Example
+/// ```rust
+/// musicbrainz_rs_nova::config::set_default_limit(100).unwrap();
+/// assert!(musicbrainz_rs_nova::config::set_default_limit(101).is_err());
+/// ```
+pub fn set_default_limit(limit: u8) -> Result<(), Error> {
+    if !(1..=100).contains(&limit) {
+        return Err(Error::InvalidLimit(limit));
+    }
+    *DEFAULT_LIMIT
+        .lock()
+        .expect("Unable to set musicbrainz default limit") = Some(limit);
+    Ok(())
+}
+
+pub(crate) fn default_limit() -> Option<u8> {
+    *DEFAULT_LIMIT
+        .lock()
+        .expect("Unable to get musicbrainz default limit")
+}
+
+/// When MusicBrainz rejects an include of a fetch query with `400 Bad Request`, retry the
+/// query without it instead of failing. The dropped includes are listed by
+/// [`FetchQuery::dropped_includes`](crate::FetchQuery::dropped_includes). Disabled by default.
+///
+/// This keeps applications working when the web service stops supporting an include.
+///
+/// ## Example
+/// ```rust
+/// musicbrainz_rs_nova::config::set_include_fallback(true);
+/// ```
+pub fn set_include_fallback(enabled: bool) {
+    *INCLUDE_FALLBACK
+        .lock()
+        .expect("Unable to set musicbrainz include fallback") = enabled;
+}
+
+pub(crate) fn include_fallback() -> bool {
+    *INCLUDE_FALLBACK
+        .lock()
+        .expect("Unable to get musicbrainz include fallback")
+}
+
+/// Send the MusicBrainz web service requests to `url` instead of `https://musicbrainz.org/ws/2`,
+/// e.g. to query a mirror. While [`set_https_only`] is enabled, `url` must use HTTPS.
+///
+/// ## Example
+/// ```rust
+/// use musicbrainz_rs_nova::config;
+///
+/// assert!(config::set_base_url("http://mirror.example.com/ws/2").is_err());
+/// ```
+pub fn set_base_url(url: &str) -> Result<(), Error> {
+    let mut settings = URL_SETTINGS
+        .lock()
+        .expect("Unable to set musicbrainz base url");
+    check_base_url(url, settings.https_only)?;
+    settings.base_url = url.trim_end_matches('/').to_string();
+    Ok(())
+}
+
+pub(crate) fn base_url() -> String {
+    URL_SETTINGS
+        .lock()
+        .expect("Unable to get musicbrainz base url")
+        .base_url
+        .clone()
+}
+
+/// Refuse any base url or redirect that doesn't use HTTPS, so no request is ever sent in plain
+/// text. Enabled by default.
+///
+/// Enabling it fails if the base url set with [`set_base_url`] doesn't use HTTPS.
+///
+/// ## Example
+/// ```rust
+/// musicbrainz_rs_nova::config::set_https_only(false).unwrap();
+/// ```
+pub fn set_https_only(enabled: bool) -> Result<(), Error> {
+    let mut settings = URL_SETTINGS
+        .lock()
+        .expect("Unable to set musicbrainz https only");
+    check_base_url(&settings.base_url, enabled)?;
+    settings.https_only = enabled;
+    Ok(())
+}
+
+pub(crate) fn https_only() -> bool {
+    URL_SETTINGS
+        .lock()
+        .expect("Unable to get musicbrainz https only")
+        .https_only
+}
+
+fn check_base_url(url: &str, https_only: bool) -> Result<(), Error> {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) if !https_only || parsed.scheme() == "https" => Ok(()),
+        _ => Err(Error::InvalidBaseUrl(url.to_string())),
+    }
 }
 
 pub fn set_default_retries(retries: u32) {
@@ -146,3 +475,222 @@ pub fn set_default_retries(retries: u32) {
         .expect("Unable to set musicbrainz client retries");
     *retries_lock = retries;
 }
+
+/// Set the OAuth2 access token sent as a bearer token with submissions, and with the queries for
+/// user data: the `user-ratings` include and collection browses.
+///
+/// Submissions need the `submit_isrc` and/or `submit_barcode` scopes, user ratings the
+/// `rating` scope and private collections the `collection` scope.
+/// See [Authentication](https://musicbrainz.org/doc/MusicBrainz_API#Authentication)
+pub fn set_auth_token(token: &str) {
+    let mut token_lock = AUTH_TOKEN
+        .lock()
+        .expect("Unable to set musicbrainz auth token");
+    *token_lock = Some(token.to_string());
+}
+
+/// Authenticate the request with the token set by [`set_auth_token`], if any.
+pub(crate) fn with_auth_token(request: RequestBuilder) -> RequestBuilder {
+    match AUTH_TOKEN
+        .lock()
+        .expect("Unable to get musicbrainz auth token")
+        .as_deref()
+    {
+        Some(token) => request.bearer_auth(token),
+        None => request,
+    }
+}
+
+/// Set the `client` parameter MusicBrainz requires on every submission, in the form
+/// `application-version`. Defaults to `musicbrainz_rs_nova-<crate version>`.
+///
+/// ## Example
+/// ```rust
+/// musicbrainz_rs_nova::config::set_submission_client("myawesometagger-1.2.0");
+/// ```
+#[cfg(feature = "submission")]
+pub fn set_submission_client(client: &str) {
+    let mut client_lock = SUBMISSION_CLIENT
+        .lock()
+        .expect("Unable to set musicbrainz submission client");
+    *client_lock = client.to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn proxied_settings(proxy_url: &str) -> HttpClientSettings {
+        HttpClientSettings {
+            user_agent: "musicbrainz_rs test",
+            proxy: Some(Proxy::all(proxy_url).unwrap().basic_auth("user", "hunter2")),
+            #[cfg(feature = "dangerous")]
+            accept_invalid_certs: false,
+        }
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn should_return_rate_limited_error() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/artist")
+            .with_status(503)
+            .with_header("retry-after", "7")
+            .create();
+
+        let err = send_with_retry_count(HTTP_CLIENT.get(&format!("{}/artist", server.url())), 0)
+            .unwrap_err();
+
+        mock.assert();
+        assert!(matches!(
+            err,
+            Error::RateLimited { retry_after: Some(duration) } if duration == Duration::from_secs(7)
+        ));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_return_rate_limited_error() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/artist")
+            .with_status(503)
+            .with_header("retry-after", "7")
+            .create_async()
+            .await;
+
+        let err = send_with_retry_count(HTTP_CLIENT.get(&format!("{}/artist", server.url())), 0)
+            .await
+            .unwrap_err();
+
+        mock.assert_async().await;
+        assert!(matches!(
+            err,
+            Error::RateLimited { retry_after: Some(duration) } if duration == Duration::from_secs(7)
+        ));
+    }
+
+    #[test]
+    fn should_parse_retry_after_header() {
+        let mut headers = header::HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(header::RETRY_AFTER, header::HeaderValue::from_static("3"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(3)));
+
+        headers.insert(
+            header::RETRY_AFTER,
+            header::HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers), None);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn should_send_requests_through_proxy() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", mockito::Matcher::Any)
+            .match_header("proxy-authorization", "Basic dXNlcjpodW50ZXIy")
+            .create();
+
+        let client = build_http_client(&proxied_settings(&server.url())).unwrap();
+        client.get("http://musicbrainz.org/ws/2").send().unwrap();
+
+        mock.assert();
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_send_requests_through_proxy() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", mockito::Matcher::Any)
+            .match_header("proxy-authorization", "Basic dXNlcjpodW50ZXIy")
+            .create_async()
+            .await;
+
+        let client = build_http_client(&proxied_settings(&server.url())).unwrap();
+        client
+            .get("http://musicbrainz.org/ws/2")
+            .send()
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn should_build_user_agent_from_cargo_package() {
+        assert_eq!(
+            crate::user_agent!("me@example.com"),
+            format!(
+                "musicbrainz_rs_nova/{} ( me@example.com )",
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+    }
+
+    #[test]
+    fn should_reject_plain_http_base_url() {
+        assert!(https_only());
+        assert!(matches!(
+            set_base_url("http://mirror.example.com/ws/2"),
+            Err(Error::InvalidBaseUrl(url)) if url == "http://mirror.example.com/ws/2"
+        ));
+        assert!(set_base_url("mirror.example.com").is_err());
+        assert_eq!(base_url(), BASE_URL);
+
+        assert!(check_base_url("http://mirror.example.com/ws/2", false).is_ok());
+        assert!(check_base_url("https://mirror.example.com/ws/2", true).is_ok());
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn should_refuse_plain_http_redirect() {
+        let mut server = mockito::Server::new();
+        let redirect = server
+            .mock("GET", "/artist")
+            .with_status(301)
+            .with_header("location", &format!("{}/moved", server.url()))
+            .create();
+
+        let err = send_with_retry_count(HTTP_CLIENT.get(&format!("{}/artist", server.url())), 0)
+            .unwrap_err();
+
+        redirect.assert();
+        assert!(matches!(err, Error::Http(err) if err.is_redirect()));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_refuse_plain_http_redirect() {
+        let mut server = mockito::Server::new_async().await;
+        let redirect = server
+            .mock("GET", "/artist")
+            .with_status(301)
+            .with_header("location", &format!("{}/moved", server.url()))
+            .create_async()
+            .await;
+
+        let err = send_with_retry_count(HTTP_CLIENT.get(&format!("{}/artist", server.url())), 0)
+            .await
+            .unwrap_err();
+
+        redirect.assert_async().await;
+        assert!(matches!(err, Error::Http(err) if err.is_redirect()));
+    }
+
+    #[cfg(feature = "dangerous")]
+    #[test]
+    fn should_toggle_invalid_certificates() {
+        set_accept_invalid_certs(true);
+        let enabled = HTTP_CLIENT_SETTINGS.lock().unwrap().accept_invalid_certs;
+        // Restore the setting before asserting, so a failure doesn't leak into other tests.
+        set_accept_invalid_certs(false);
+
+        assert!(enabled);
+        assert!(!HTTP_CLIENT_SETTINGS.lock().unwrap().accept_invalid_certs);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::entity::search::Searchable;
+use crate::entity::Browsable;
+use serde::de::{self, Deserialize, Deserializer, IgnoredAny, MapAccess, Visitor};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// The total count of a browse response, read without deserializing the entities.
+pub(crate) struct BrowseCount<T> {
+    pub(crate) count: u32,
+    phantom: PhantomData<T>,
+}
+
+/// The total count of a search response, read without deserializing the entities.
+pub(crate) struct SearchCount<T> {
+    pub(crate) count: u32,
+    phantom: PhantomData<T>,
+}
+
+/// Read the `count` field of a response and skip everything else.
+struct CountVisitor {
+    field: &'static str,
+}
+
+impl<'de> Visitor<'de> for CountVisitor {
+    type Value = u32;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a map with a `{}` field", self.field)
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<u32, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let mut count = None;
+        while let Some(key) = map.next_key::<String>()? {
+            if key != self.field {
+                map.next_value::<IgnoredAny>()?;
+            } else if count.is_some() {
+                return Err(de::Error::duplicate_field(self.field));
+            } else {
+                count = Some(map.next_value()?);
+            }
+        }
+        count.ok_or_else(|| de::Error::missing_field(self.field))
+    }
+}
+
+impl<'de, T> Deserialize<'de> for BrowseCount<T>
+where
+    T: Browsable,
+{
+    fn deserialize<D>(deserializer: D) -> Result<BrowseCount<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let field = T::COUNT_FIELD;
+        let count = deserializer.deserialize_map(CountVisitor { field })?;
+        Ok(BrowseCount {
+            count,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl<'de, T> Deserialize<'de> for SearchCount<T>
+where
+    T: Searchable,
+{
+    fn deserialize<D>(deserializer: D) -> Result<SearchCount<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let field = T::COUNT_FIELD;
+        let count = deserializer.deserialize_map(CountVisitor { field })?;
+        Ok(SearchCount {
+            count,
+            phantom: PhantomData,
+        })
+    }
+}
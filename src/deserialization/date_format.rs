@@ -3,31 +3,32 @@ use serde::{self, Deserialize, Deserializer};
 
 const FORMAT: &str = "%Y-%m-%d";
 
-/// Some times, music brainz dates doesn't have a month or day
-/// This artificially add january 1st before deserializing the API response
-pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
+/// Deserialize an optional MusicBrainz date.
+/// MusicBrainz sometimes sends `""` instead of omitting the date or sending `null`: all of these
+/// are deserialized as `None`, as well as dates that can't be parsed.
+pub(crate) fn deserialize_opt<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let s = String::deserialize(deserializer)?;
-    NaiveDate::parse_from_str(&s, FORMAT)
-        .or_else(|_err| NaiveDate::parse_from_str(&format!("{}-01", &s), FORMAT))
-        .or_else(|_err| NaiveDate::parse_from_str(&format!("{}-01-01", &s), FORMAT))
-        .map_err(serde::de::Error::custom)
+    let s: Option<String> = Option::deserialize(deserializer)?;
+    match s.as_deref().map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(s) => Ok(parse_date(s).ok()),
+    }
 }
 
-/// This is the `Option<NaiveDate>` version of the previous deserializer
-#[allow(clippy::unnecessary_wraps)]
-pub(crate) fn deserialize_opt<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    Ok(deserialize(deserializer).ok())
+/// Some times, music brainz dates doesn't have a month or day
+/// This artificially add january 1st before parsing the date
+fn parse_date(s: &str) -> Result<NaiveDate, chrono::ParseError> {
+    NaiveDate::parse_from_str(s, FORMAT)
+        .or_else(|_err| NaiveDate::parse_from_str(&format!("{}-01", s), FORMAT))
+        .or_else(|_err| NaiveDate::parse_from_str(&format!("{}-01-01", s), FORMAT))
 }
 
 #[cfg(test)]
 mod tests {
     use crate::entity::lifespan::LifeSpan;
+    use crate::entity::release::Release;
     use chrono::NaiveDate;
 
     #[test]
@@ -90,4 +91,59 @@ mod tests {
             }
         )
     }
+
+    #[test]
+    fn should_deserialize_null_date() {
+        let input = r#"
+            {
+                "ended": false,
+                "end": null,
+                "begin": null
+            }"#;
+
+        let life_span: LifeSpan = serde_json::from_str(input).unwrap();
+
+        assert_eq!(
+            life_span,
+            LifeSpan {
+                ended: Some(false),
+                begin: None,
+                end: None,
+            }
+        )
+    }
+
+    #[test]
+    fn should_deserialize_full_date() {
+        let input = r#"
+            {
+                "ended": false,
+                "begin": "1991-09-24"
+            }"#;
+
+        let life_span: LifeSpan = serde_json::from_str(input).unwrap();
+
+        assert_eq!(
+            life_span,
+            LifeSpan {
+                ended: Some(false),
+                begin: Some(NaiveDate::from_ymd_opt(1991, 9, 24).unwrap()),
+                end: None,
+            }
+        )
+    }
+
+    #[test]
+    fn should_deserialize_empty_release_date() {
+        let input = r#"
+            {
+                "id": "18d4e9b4-9247-4b44-914a-8ddec3502103",
+                "title": "In Utero",
+                "date": ""
+            }"#;
+
+        let release: Release = serde_json::from_str(input).unwrap();
+
+        assert_eq!(release.date, None);
+    }
 }
@@ -0,0 +1,88 @@
+//! The JSON parser decoding the web service responses: serde_json by default, or
+//! [simd-json](https://docs.rs/simd-json) with the `simd-json` feature. Whether simd-json is
+//! faster depends on the CPU and the response shapes: measure it on your workload before
+//! switching, e.g. with `cargo bench --bench json_parsing --features simd-json`.
+
+use serde::de::DeserializeOwned;
+
+use crate::Error;
+
+#[cfg(not(feature = "simd-json"))]
+pub(crate) fn from_bytes<T: DeserializeOwned>(body: Vec<u8>) -> Result<T, Error> {
+    serde_json::from_slice(&body).map_err(|err| Error::Json(Box::new(err)))
+}
+
+#[cfg(feature = "simd-json")]
+pub(crate) fn from_bytes<T: DeserializeOwned>(mut body: Vec<u8>) -> Result<T, Error> {
+    // simd-json parses in place, hence the owned buffer.
+    simd_json::serde::from_slice(&mut body).map_err(|err| Error::Json(Box::new(err)))
+}
+
+#[cfg(feature = "blocking")]
+pub(crate) fn from_response<T: DeserializeOwned>(
+    response: reqwest::blocking::Response,
+) -> Result<T, Error> {
+    from_bytes(response.bytes()?.to_vec())
+}
+
+#[cfg(feature = "async")]
+pub(crate) async fn from_response<T: DeserializeOwned>(
+    response: reqwest::Response,
+) -> Result<T, Error> {
+    from_bytes(response.bytes().await?.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::release::Release;
+    use crate::entity::BrowseResult;
+
+    fn browse_fixture(count: usize) -> Vec<u8> {
+        let releases: Vec<_> = (0..count)
+            .map(|i| {
+                serde_json::json!({
+                    "id": format!("18d4e9b4-9247-4b44-914a-{:012}", i),
+                    "title": "Bleach",
+                    "status": "Official",
+                    "packaging": "Jewel Case",
+                    "date": "1989-06-15",
+                    "country": "US",
+                    "barcode": "098787003420",
+                    "disambiguation": "",
+                    "text-representation": {"language": "eng", "script": "Latn"},
+                    "release-events": [{"date": "1989-06-15", "area": {
+                        "id": "489ce91b-6658-3307-9877-795b68554c98",
+                        "name": "United States",
+                        "sort-name": "United States",
+                        "iso-3166-1-codes": ["US"]
+                    }}],
+                    "media": [{"position": 1, "format": "CD", "track-count": 13, "title": ""}]
+                })
+            })
+            .collect();
+
+        serde_json::to_vec(&serde_json::json!({
+            "release-count": count,
+            "release-offset": 0,
+            "releases": releases,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn should_parse_browse_response() {
+        let result: BrowseResult<Release> = from_bytes(browse_fixture(3)).unwrap();
+
+        assert_eq!(result.count, 3);
+        assert_eq!(result.entities.len(), 3);
+        assert_eq!(result.entities[2].title, "Bleach");
+    }
+
+    #[test]
+    fn should_report_invalid_json() {
+        let result: Result<Release, _> = from_bytes(b"{\"id\": ".to_vec());
+
+        assert!(matches!(result, Err(Error::Json(_))));
+    }
+}
@@ -1,3 +1,5 @@
 pub(crate) mod browse_deserializer;
+pub(crate) mod count_deserializer;
 pub(crate) mod date_format;
+pub(crate) mod json;
 pub(crate) mod search_deserializer;
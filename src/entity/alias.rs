@@ -1,6 +1,8 @@
 use crate::date_format;
+use crate::entity::lifespan::LifeSpan;
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Aliases are used to store alternate names or misspellings. For more information and examples,
 /// see the page about [aliases](https://musicbrainz.org/doc/Aliases).
@@ -17,8 +19,238 @@ pub struct Alias {
     #[serde(default)]
     #[serde(deserialize_with = "date_format::deserialize_opt")]
     pub end: Option<NaiveDate>,
+    /// The kind of alias, e.g. a legal name or a search hint.
     #[serde(rename = "type")]
-    pub alias_type: Option<String>,
+    pub alias_type: Option<AliasType>,
+    /// Whether this alias is the preferred name in its `locale`.
     pub primary: Option<bool>,
     pub type_id: Option<String>,
+    /// The locale this alias is a name in, e.g. `ja` or `en_US`.
+    pub locale: Option<String>,
+}
+
+impl Alias {
+    /// The key [`Alias::group_by_locale`] files the aliases without a locale under.
+    pub const NO_LOCALE: &'static str = "";
+
+    /// The period during which the alias was used, e.g. the years a label traded under a
+    /// former name, as a [`LifeSpan`].
+    pub fn life_span(&self) -> LifeSpan {
+        LifeSpan {
+            ended: self.ended,
+            begin: self.begin,
+            end: self.end,
+        }
+    }
+
+    /// The alias to display for `locale`, e.g. `ja` or `en-US`: an alias in that exact locale,
+    /// or else in the same language (`ja_JP` matches `ja`). Primary aliases come first. Search
+    /// hints and ended aliases are never picked.
+    pub fn best_for_locale<'a>(aliases: &'a [Alias], locale: &str) -> Option<&'a Alias> {
+        let wanted = normalize_locale(locale);
+        let wanted_language = language(&wanted);
+
+        aliases
+            .iter()
+            .filter(|alias| alias.alias_type != Some(AliasType::SearchHint))
+            .filter(|alias| alias.ended != Some(true))
+            .filter_map(|alias| {
+                let alias_locale = normalize_locale(alias.locale.as_deref()?);
+                let rank = if alias_locale == wanted {
+                    0
+                } else if language(&alias_locale) == wanted_language {
+                    1
+                } else {
+                    return None;
+                };
+                Some(((rank, alias.primary != Some(true)), alias))
+            })
+            .min_by_key(|(rank, _)| *rank)
+            .map(|(_, alias)| alias)
+    }
+
+    /// Group the aliases by locale, e.g. to build a language picker. Aliases without a locale
+    /// are grouped under [`Alias::NO_LOCALE`]. In each group, the primary alias comes first,
+    /// the others keep their order.
+    pub fn group_by_locale(aliases: &[Alias]) -> HashMap<String, Vec<Alias>> {
+        let mut groups: HashMap<String, Vec<Alias>> = HashMap::new();
+        for alias in aliases {
+            let locale = alias.locale.as_deref().unwrap_or(Alias::NO_LOCALE);
+            groups
+                .entry(locale.to_string())
+                .or_default()
+                .push(alias.clone());
+        }
+        for group in groups.values_mut() {
+            group.sort_by_key(|alias| alias.primary != Some(true));
+        }
+        groups
+    }
+}
+
+fn normalize_locale(locale: &str) -> String {
+    locale.trim().replace('-', "_").to_ascii_lowercase()
+}
+
+fn language(locale: &str) -> &str {
+    locale.split('_').next().unwrap_or(locale)
+}
+
+/// The type of a MusicBrainz alias.
+/// Note that this enum is `non_exhaustive`; The list of alias types is subject to change and these
+/// changes are only reflected in the DB, not in actual MB code.
+/// Variants are derived from the `*_alias_type` tables in the MusicBrainz database.
+#[non_exhaustive]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "tracing", serde(remote = "Self"))]
+pub enum AliasType {
+    /// An alternate name the artist performs or is credited under.
+    #[serde(rename = "Artist name")]
+    ArtistName,
+    /// The legal name of a person artist, if different from its performance name.
+    #[serde(rename = "Legal name")]
+    LegalName,
+    #[serde(rename = "Area name")]
+    AreaName,
+    /// The official name of an area, e.g. "Kingdom of Sweden" for Sweden.
+    #[serde(rename = "Formal name")]
+    FormalName,
+    #[serde(rename = "Event name")]
+    EventName,
+    #[serde(rename = "Genre name")]
+    GenreName,
+    #[serde(rename = "Instrument name")]
+    InstrumentName,
+    #[serde(rename = "Label name")]
+    LabelName,
+    #[serde(rename = "Place name")]
+    PlaceName,
+    #[serde(rename = "Recording name")]
+    RecordingName,
+    #[serde(rename = "Release name")]
+    ReleaseName,
+    #[serde(rename = "Release group name")]
+    ReleaseGroupName,
+    #[serde(rename = "Series name")]
+    SeriesName,
+    #[serde(rename = "Work name")]
+    WorkName,
+    /// Alternate spellings, misspellings or nicknames that only help searching for the entity.
+    /// Search hints are not displayed as names of the entity.
+    #[serde(rename = "Search hint")]
+    SearchHint,
+    /// Any alias type that does not yet have a corresponding variant in this enum.
+    /// If you ever see a `AliasType::UnrecognizedAliasType` in the wild, let us know and file an issue/pull request!
+    #[serde(other)]
+    UnrecognizedAliasType,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_deserialize_alias_types() {
+        let input = r#"
+            [
+                {
+                    "name": "Delta John",
+                    "sort-name": "Delta John",
+                    "type": "Artist name",
+                    "type-id": "894afba6-2816-3c24-8072-eadb66bd04bc",
+                    "primary": null,
+                    "locale": null,
+                    "begin": null,
+                    "end": null,
+                    "ended": false
+                },
+                {
+                    "name": "John Lee Hooker",
+                    "sort-name": "Hooker, John Lee",
+                    "type": "Legal name",
+                    "type-id": "d4dcd0c0-b341-3612-a332-c0ce797b25cf"
+                },
+                {
+                    "name": "John Lee Hocker",
+                    "sort-name": "Hocker, John Lee",
+                    "type": "Search hint",
+                    "type-id": "1937e404-b981-3cb7-8151-4c86ebfc8d8e"
+                },
+                {
+                    "name": "J. L. Hooker",
+                    "sort-name": "Hooker, J. L.",
+                    "type": "Nickname"
+                },
+                {
+                    "name": "Texas Slim",
+                    "sort-name": "Texas Slim",
+                    "type": null
+                }
+            ]"#;
+
+        let aliases: Vec<Alias> = serde_json::from_str(input).unwrap();
+        let types: Vec<Option<AliasType>> = aliases.into_iter().map(|a| a.alias_type).collect();
+
+        assert_eq!(
+            types,
+            vec![
+                Some(AliasType::ArtistName),
+                Some(AliasType::LegalName),
+                Some(AliasType::SearchHint),
+                Some(AliasType::UnrecognizedAliasType),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn should_pick_best_alias_for_locale() {
+        let input = r#"[
+            {"name": "Sakamoto Ryūichi", "sort-name": "Sakamoto, Ryūichi", "locale": "ja",
+             "type": "Search hint", "primary": null},
+            {"name": "さかもとりゅういち", "sort-name": "さかもとりゅういち", "locale": "ja",
+             "type": "Artist name", "primary": null},
+            {"name": "坂本龍一", "sort-name": "坂本龍一", "locale": "ja",
+             "type": "Artist name", "primary": true},
+            {"name": "Ryuichi Sakamoto", "sort-name": "Sakamoto, Ryuichi", "locale": "en",
+             "type": "Artist name", "primary": true},
+            {"name": "Riuichi Sakamoto", "sort-name": "Sakamoto, Riuichi", "locale": "pt_BR",
+             "type": "Artist name", "ended": true}
+        ]"#;
+        let aliases: Vec<Alias> = serde_json::from_str(input).unwrap();
+        let best =
+            |locale| Alias::best_for_locale(&aliases, locale).map(|alias| alias.name.as_str());
+
+        assert_eq!(best("ja"), Some("坂本龍一"));
+        assert_eq!(best("ja-JP"), Some("坂本龍一"));
+        assert_eq!(best("EN_us"), Some("Ryuichi Sakamoto"));
+        assert_eq!(best("pt_BR"), None);
+        assert_eq!(best("fr"), None);
+    }
+
+    #[test]
+    fn should_read_alias_period() {
+        let input = r#"{
+            "name": "Prince Rogers Nelson",
+            "sort-name": "Nelson, Prince Rogers",
+            "type": "Artist name",
+            "locale": null,
+            "primary": null,
+            "begin": "1993-06",
+            "end": "2000-05-16",
+            "ended": true
+        }"#;
+
+        let alias: Alias = serde_json::from_str(input).unwrap();
+
+        assert_eq!(
+            alias.life_span(),
+            LifeSpan {
+                ended: Some(true),
+                begin: NaiveDate::from_ymd_opt(1993, 6, 1),
+                end: NaiveDate::from_ymd_opt(2000, 5, 16),
+            }
+        );
+        assert_eq!(Alias::default().life_span(), LifeSpan::default());
+    }
 }
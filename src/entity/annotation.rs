@@ -37,3 +37,5 @@ pub struct AnnotationSearchQuery {
     #[query_builder_field = "type"]
     pub annotation_type: String,
 }
+
+impl_try_build!(AnnotationSearchQueryLuceneQueryBuilder);
@@ -2,7 +2,8 @@ use super::{Include, Relationship, Subquery};
 use crate::entity::alias::Alias;
 use crate::entity::genre::Genre;
 use crate::entity::lifespan::LifeSpan;
-use crate::entity::relations::Relation;
+use crate::entity::mbid::Mbid;
+use crate::entity::relations::{Relation, RelationContent};
 use crate::entity::tag::Tag;
 use crate::entity::BrowseBy;
 
@@ -12,42 +13,80 @@ use serde::{Deserialize, Serialize};
 
 /// Areas are historical and existing geographic regions. Areas include countries, sub-divisions,
 /// counties, municipalities, cities, districts and islands.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 #[serde(rename_all(deserialize = "kebab-case"))]
-#[serde(default)]
 pub struct Area {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
     /// The type of area. Possible values are: Country, Subdivision, County, Municipality, City,
     /// District, Island.
     #[serde(rename = "type")]
+    #[serde(default)]
     pub area_type: Option<AreaType>,
     /// The name of the area.
+    #[serde(default)]
     pub name: String,
+    #[serde(default)]
     pub relations: Option<Vec<Relation>>,
     /// The ISO 3166 codes are the codes assigned by ISO to countries and subdivisions.
+    #[serde(default)]
     pub iso_3166_1_codes: Option<Vec<String>>,
     /// The aliases are used to store alternate names or misspellings.
+    #[serde(default)]
     pub aliases: Option<Vec<Alias>>,
     /// Annotations are text fields, functioning like a miniature wiki, that can be added to any
     /// existing artists, labels, recordings, releases, release groups and works.
+    #[serde(default)]
     pub annotation: Option<String>,
     /// The disambiguation comments are fields in the database used to help distinguish identically
     /// named artists, labels and other entities.
+    #[serde(default)]
     pub disambiguation: String,
+    #[serde(default)]
     pub type_id: Option<String>,
+    #[serde(default)]
     pub sort_name: String,
+    #[serde(default)]
     pub life_span: Option<LifeSpan>,
+    #[serde(default)]
     pub tags: Option<Vec<Tag>>,
+    #[serde(default)]
     pub genres: Option<Vec<Genre>>,
 }
 
+impl Area {
+    /// The first [ISO 3166-1](https://en.wikipedia.org/wiki/ISO_3166-1) code of the area. Only
+    /// countries have one.
+    pub fn country_code(&self) -> Option<&str> {
+        self.iso_3166_1_codes
+            .as_ref()
+            .and_then(|codes| codes.first())
+            .map(String::as_str)
+    }
+
+    /// The area this area is "part of", e.g. the country of a subdivision. Requires the area to
+    /// be fetched with [`with_area_relations`](crate::FetchQuery::with_area_relations).
+    pub fn parent_area(&self) -> Option<&Area> {
+        self.relations
+            .iter()
+            .flatten()
+            .filter(|relation| {
+                relation.relation_type == "part of" && relation.direction == "backward"
+            })
+            .find_map(|relation| match &relation.content {
+                RelationContent::Area(area) => Some(area.as_ref()),
+                _ => None,
+            })
+    }
+}
+
 /// The type of a MusicBrainz area entity.
 /// Note that this enum is `non_exhaustive`; The list of area types is subject to change and these
 /// changes are only reflected in the DB, not in actual MB code.
 /// Variants are derived from the `area_type` table in the MusicBrainz database.
 #[non_exhaustive]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "tracing", serde(remote = "Self"))]
 pub enum AreaType {
     /// Country is used for areas included (or previously included) in ISO 3166-1, e.g. United
     /// States.
@@ -123,6 +162,8 @@ pub struct AreaSearchQuery {
     area_type: String,
 }
 
+impl_try_build!(AreaSearchQueryLuceneQueryBuilder);
+
 impl_browse!(Area, (by_collection, BrowseBy::Collection));
 
 impl_includes!(
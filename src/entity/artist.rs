@@ -3,6 +3,7 @@ use crate::entity::alias::Alias;
 use crate::entity::area::Area;
 use crate::entity::genre::Genre;
 use crate::entity::lifespan::LifeSpan;
+use crate::entity::mbid::Mbid;
 use crate::entity::rating::Rating;
 use crate::entity::recording::Recording;
 use crate::entity::relations::Relation;
@@ -11,95 +12,127 @@ use crate::entity::release_group::ReleaseGroup;
 use crate::entity::tag::Tag;
 use crate::entity::work::Work;
 use crate::entity::BrowseBy;
+use crate::Error;
+use crate::Fetch;
 use chrono::NaiveDate;
 use lucene_query_builder::QueryBuilder;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// An artist is generally a musician (or musician persona), group of musicians, or other music
 /// professional (like a producer or engineer). Occasionally, it can also be a non-musical person
 /// (like a photographer, an illustrator, or a poet whose writings are set to music), or even a
 /// fictional character. For some other special cases, see special purpose artists.
-#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 #[serde(rename_all(deserialize = "kebab-case"))]
-#[serde(default)]
 pub struct Artist {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
     /// The official name of an artist, be it a person or a band.
+    #[serde(default)]
     pub name: String,
     /// The sort name is a variant of the artist name which would be used when sorting artists by
     /// name, such as in record shops or libraries. Among other things, sort names help to ensure
     /// that all the artists that start with "The" don't end up up under "T". The guidelines for
     /// sort names are the best place to check for more specific usage info.
+    #[serde(default)]
     pub sort_name: String,
     /// The disambiguation comments are fields in the database used to help distinguish identically
     /// named artists, labels and other entities.
+    #[serde(default)]
     pub disambiguation: String,
 
     /// The type is used to state whether an artist is a person, a group, or something else.
     #[serde(rename = "type")]
+    #[serde(default)]
     pub artist_type: Option<ArtistType>,
 
     /// The gender is used to explicitly state whether a person or character identifies as male,
     /// female or neither. Groups do not have genders.
+    #[serde(default)]
     pub gender: Option<Gender>,
+    #[serde(default)]
+    pub gender_id: Option<String>,
 
     /// The artist area, as the name suggests, indicates the area with which an artist is primarily
     /// identified with. It is often, but not always, its birth/formation country.
+    #[serde(default)]
     pub area: Option<Area>,
 
     ///The artist begin area, as the name suggests, indicates the area with which an artist started
     /// to perform.
     #[serde(rename = "begin_area")] // Forcing camel_case here since
+    #[serde(default)]
     pub begin_area: Option<Area>, // all other field are deserialized in kebab-case
 
+    /// The artist end area, as the name suggests, indicates the area with which an artist ended
+    /// its existence (e.g. place of death, or where a group dissolved).
+    #[serde(rename = "end_area")]
+    #[serde(default)]
+    pub end_area: Option<Area>,
+
+    #[serde(default)]
     pub relations: Option<Vec<Relation>>,
     /// release represents the unique release (i.e. issuing) of a product on a specific date with
     /// specific release information such as the country, label, barcode and packaging.
+    #[serde(default)]
     pub releases: Option<Vec<Release>>,
     /// A work is a distinct intellectual or artistic creation, which can be expressed in the form of
     /// one or more audio recordings.
+    #[serde(default)]
     pub works: Option<Vec<Work>>,
+    #[serde(default)]
     pub release_groups: Option<Vec<ReleaseGroup>>,
+    #[serde(default)]
     pub recordings: Option<Vec<Recording>>,
 
     /// Aliases are used to store alternate names or misspellings. For more information and examples,
     /// see the page about aliases.
+    #[serde(default)]
     pub aliases: Option<Vec<Alias>>,
+    #[serde(default)]
     pub tags: Option<Vec<Tag>>,
 
     /// Genres are currently supported in MusicBrainz as part of the tag system.
+    #[serde(default)]
     pub genres: Option<Vec<Genre>>,
+    #[serde(default)]
     pub rating: Option<Rating>,
+    /// The rating the authenticated user gave, loaded with the `user-ratings` include.
+    #[serde(default)]
+    pub user_rating: Option<Rating>,
+    #[serde(default)]
     pub country: Option<String>,
 
     /// Annotations are text fields, functioning like a miniature wiki, that can be added to any existing
     /// artists, labels, recordings, releases, release groups and works.
+    #[serde(default)]
     pub annotation: Option<String>,
 
     /// The begin and end dates indicate when an artist started and finished its existence.
     /// Its exact meaning depends on the type of artist:
     ///
-    ///  - For a person
-    ///        Begin date represents date of birth, and end date represents date of death.
+    /// - For a person:
+    ///   Begin date represents date of birth, and end date represents date of death.
     ///
-    ///    - For a group (or orchestra/choir)
-    ///        Begin date represents the date when the group first formed: if a group dissolved and then
-    ///        reunited, the date is still that of when they first formed. End date represents the date
-    ///        when the group last dissolved: if a group dissolved and then reunited, the date is that
-    ///        of when they last dissolved (if they are together, it should be blank!). For listing
-    ///        other inactivity periods, just use the annotation and the "member of" relationships.
+    /// - For a group (or orchestra/choir):
+    ///   Begin date represents the date when the group first formed: if a group dissolved and then
+    ///   reunited, the date is still that of when they first formed. End date represents the date
+    ///   when the group last dissolved: if a group dissolved and then reunited, the date is that
+    ///   of when they last dissolved (if they are together, it should be blank!). For listing
+    ///   other inactivity periods, just use the annotation and the "member of" relationships.
     ///
-    ///    - For a character
-    ///        Begin date represents the date (in real life) when the character concept was created.
-    ///        The End date should not be set, since new media featuring a character can be created
-    ///        at any time. In particular, the Begin and End date fields should not be used to hold
-    ///        the fictional birth or death dates of a character.
-    ///        (This information can be put in the annotation.)
+    /// - For a character:
+    ///   Begin date represents the date (in real life) when the character concept was created.
+    ///   The End date should not be set, since new media featuring a character can be created
+    ///   at any time. In particular, the Begin and End date fields should not be used to hold
+    ///   the fictional birth or death dates of a character.
+    ///   (This information can be put in the annotation.)
     ///
-    ///    - For others
-    ///        There are no clear indications about how to use dates for artists of the type Other at
-    ///        the moment.
+    /// - For others:
+    ///   There are no clear indications about how to use dates for artists of the type Other at
+    ///   the moment.
+    #[serde(default)]
     pub life_span: Option<LifeSpan>,
 }
 
@@ -109,6 +142,7 @@ pub struct Artist {
 /// Variants are derived from the `artist_type` table in the MusicBrainz database.
 #[non_exhaustive]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "tracing", serde(remote = "Self"))]
 pub enum ArtistType {
     /// This indicates a choir/chorus (an organized, usually large group of singers). Smaller
     /// vocal ensembles and groupings that do not generally call themselves choirs are better
@@ -143,6 +177,7 @@ pub enum ArtistType {
 /// Variants are derived from the `gender` table in the MusicBrainz database.
 #[non_exhaustive]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "tracing", serde(remote = "Self"))]
 pub enum Gender {
     Male,
     Female,
@@ -157,6 +192,20 @@ pub enum Gender {
     UnrecognizedGender,
 }
 
+impl Gender {
+    /// The lowercase value used by the search API `gender` field. `UnrecognizedGender` maps to an
+    /// empty string since the original value is not kept.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Gender::Male => "male",
+            Gender::Female => "female",
+            Gender::Other => "other",
+            Gender::NotApplicable => "not applicable",
+            Gender::UnrecognizedGender => "",
+        }
+    }
+}
+
 #[derive(Debug, QueryBuilder, Default)]
 pub struct ArtistSearchQuery {
     /// (part of) any alias attached to the artist (diacritics are ignored)
@@ -203,6 +252,124 @@ pub struct ArtistSearchQuery {
     pub artist_type: Option<ArtistType>,
 }
 
+impl_try_build!(ArtistSearchQueryLuceneQueryBuilder);
+
+impl Artist {
+    /// Fetch the full [`Area`] entity of the artist begin area. The begin area returned along
+    /// with the artist only contains a few fields, like its id and name.
+    /// Returns `None` if the artist has no begin area.
+    #[cfg(feature = "blocking")]
+    pub fn fetch_begin_area(&self) -> Result<Option<Area>, Error> {
+        fetch_area(self.begin_area.as_ref())
+    }
+
+    /// Fetch the full [`Area`] entity of the artist begin area. The begin area returned along
+    /// with the artist only contains a few fields, like its id and name.
+    /// Returns `None` if the artist has no begin area.
+    #[cfg(feature = "async")]
+    pub async fn fetch_begin_area(&self) -> Result<Option<Area>, Error> {
+        fetch_area(self.begin_area.as_ref()).await
+    }
+
+    /// Fetch the full [`Area`] entity of the artist end area.
+    /// Returns `None` if the artist has no end area.
+    #[cfg(feature = "blocking")]
+    pub fn fetch_end_area(&self) -> Result<Option<Area>, Error> {
+        fetch_area(self.end_area.as_ref())
+    }
+
+    /// Fetch the full [`Area`] entity of the artist end area.
+    /// Returns `None` if the artist has no end area.
+    #[cfg(feature = "async")]
+    pub async fn fetch_end_area(&self) -> Result<Option<Area>, Error> {
+        fetch_area(self.end_area.as_ref()).await
+    }
+
+    /// The loaded aliases grouped by locale, see [`Alias::group_by_locale`]. Empty unless the
+    /// artist was fetched [`with_aliases`](crate::FetchQuery::with_aliases).
+    pub fn aliases_by_locale(&self) -> HashMap<String, Vec<Alias>> {
+        Alias::group_by_locale(self.aliases.as_deref().unwrap_or_default())
+    }
+
+    /// The name to display in `locale` (e.g. `ja` or `en_US`): the best alias for that locale
+    /// (see [`Alias::best_for_locale`]), or the artist name if there is none.
+    ///
+    /// The aliases are fetched once if the artist was not fetched
+    /// [`with_aliases`](crate::FetchQuery::with_aliases).
+    #[cfg(feature = "blocking")]
+    pub fn localized_name(&self, locale: &str) -> Result<String, Error> {
+        localized_name(&crate::config::base_url(), self, locale)
+    }
+
+    /// The name to display in `locale` (e.g. `ja` or `en_US`): the best alias for that locale
+    /// (see [`Alias::best_for_locale`]), or the artist name if there is none.
+    ///
+    /// The aliases are fetched once if the artist was not fetched
+    /// [`with_aliases`](crate::FetchQuery::with_aliases).
+    #[cfg(feature = "async")]
+    pub async fn localized_name(&self, locale: &str) -> Result<String, Error> {
+        localized_name(&crate::config::base_url(), self, locale).await
+    }
+}
+
+fn alias_or_name(name: &str, aliases: &[Alias], locale: &str) -> String {
+    Alias::best_for_locale(aliases, locale)
+        .map_or(name, |alias| alias.name.as_str())
+        .to_string()
+}
+
+#[cfg(feature = "blocking")]
+fn localized_name(base_url: &str, artist: &Artist, locale: &str) -> Result<String, Error> {
+    if let Some(aliases) = &artist.aliases {
+        return Ok(alias_or_name(&artist.name, aliases, locale));
+    }
+
+    let fetched = crate::fetch_query_at::<Artist>(base_url)
+        .id(&artist.id)
+        .with_aliases()
+        .execute()?;
+    let aliases = fetched.aliases.unwrap_or_default();
+    Ok(alias_or_name(&artist.name, &aliases, locale))
+}
+
+#[cfg(feature = "async")]
+async fn localized_name(base_url: &str, artist: &Artist, locale: &str) -> Result<String, Error> {
+    if let Some(aliases) = &artist.aliases {
+        return Ok(alias_or_name(&artist.name, aliases, locale));
+    }
+
+    let fetched = crate::fetch_query_at::<Artist>(base_url)
+        .id(&artist.id)
+        .with_aliases()
+        .execute()
+        .await?;
+    let aliases = fetched.aliases.unwrap_or_default();
+    Ok(alias_or_name(&artist.name, &aliases, locale))
+}
+
+#[cfg(feature = "blocking")]
+fn fetch_area(area: Option<&Area>) -> Result<Option<Area>, Error> {
+    match area {
+        Some(area) => Area::fetch().id(&area.id).execute().map(Some),
+        None => Ok(None),
+    }
+}
+
+#[cfg(feature = "async")]
+async fn fetch_area(area: Option<&Area>) -> Result<Option<Area>, Error> {
+    match area {
+        Some(area) => Area::fetch().id(&area.id).execute().await.map(Some),
+        None => Ok(None),
+    }
+}
+
+impl ArtistSearchQueryLuceneQueryBuilder {
+    /// Typed version of the `gender` setter.
+    pub fn gender_typed(&mut self, gender: Gender) -> &mut Self {
+        self.gender(gender.as_str())
+    }
+}
+
 impl_browse! {
 Artist,
    (by_area, BrowseBy::Area),
@@ -254,6 +421,284 @@ impl_includes!(
     ),
     (with_tags, Include::Subquery(Subquery::Tags)),
     (with_rating, Include::Subquery(Subquery::Rating)),
+    (with_user_ratings, Include::Subquery(Subquery::UserRatings)),
     (with_genres, Include::Subquery(Subquery::Genres)),
     (with_annotations, Include::Subquery(Subquery::Annotations))
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_deserialize_artist_gender() {
+        let input = r#"
+            {
+                "id": "f4abc0b5-3f7a-4eff-8f78-ac078dbce533",
+                "name": "Billie Eilish",
+                "sort-name": "Eilish, Billie",
+                "type": "Person",
+                "gender": "Female",
+                "gender-id": "93452b5a-a947-30c8-934f-6a4056b151c2"
+            }"#;
+
+        let artist: Artist = serde_json::from_str(input).unwrap();
+
+        assert_eq!(artist.gender, Some(Gender::Female));
+        assert_eq!(
+            artist.gender_id,
+            Some("93452b5a-a947-30c8-934f-6a4056b151c2".to_string())
+        );
+    }
+
+    #[test]
+    fn should_deserialize_artist_without_gender() {
+        let input = r#"
+            {
+                "id": "5b11f4ce-a62d-471e-81fc-a69a8278c7da",
+                "name": "Nirvana",
+                "sort-name": "Nirvana",
+                "type": "Group",
+                "gender": null,
+                "gender-id": null
+            }"#;
+
+        let artist: Artist = serde_json::from_str(input).unwrap();
+
+        assert_eq!(artist.gender, None);
+        assert_eq!(artist.gender_id, None);
+    }
+
+    #[test]
+    fn should_deserialize_artist_begin_and_end_areas() {
+        let input = r#"
+            {
+                "id": "5b11f4ce-a62d-471e-81fc-a69a8278c7da",
+                "name": "Nirvana",
+                "sort-name": "Nirvana",
+                "begin_area": {
+                    "id": "a640b45c-c173-49b1-8030-973603e895b5",
+                    "name": "Aberdeen",
+                    "sort-name": "Aberdeen",
+                    "disambiguation": ""
+                },
+                "end_area": {
+                    "id": "7e2f9a8d-6c16-4e3b-8bba-2b0f40c0a1f5",
+                    "name": "Seattle",
+                    "sort-name": "Seattle"
+                }
+            }"#;
+
+        let artist: Artist = serde_json::from_str(input).unwrap();
+        let begin_area = artist.begin_area.unwrap();
+        let end_area = artist.end_area.unwrap();
+
+        assert_eq!(begin_area.id, "a640b45c-c173-49b1-8030-973603e895b5");
+        assert_eq!(begin_area.name, "Aberdeen");
+        assert_eq!(end_area.id, "7e2f9a8d-6c16-4e3b-8bba-2b0f40c0a1f5");
+        assert_eq!(end_area.name, "Seattle");
+    }
+
+    #[test]
+    fn should_deserialize_not_applicable_gender() {
+        let gender: Gender = serde_json::from_str(r#""Not applicable""#).unwrap();
+
+        assert_eq!(gender, Gender::NotApplicable);
+    }
+
+    #[test]
+    fn should_build_typed_gender_query() {
+        let query = ArtistSearchQuery::query_builder()
+            .gender_typed(Gender::NotApplicable)
+            .build();
+
+        assert_eq!(query, r#"query=gender:"not applicable""#);
+    }
+
+    #[test]
+    fn should_reject_empty_search_query() {
+        let empty = ArtistSearchQuery::query_builder().try_build();
+        let only_operators = ArtistSearchQuery::query_builder().and().try_build();
+        let query = ArtistSearchQuery::query_builder()
+            .artist("Nirvana")
+            .try_build()
+            .unwrap();
+
+        assert!(matches!(empty, Err(Error::EmptySearchQuery)));
+        assert!(matches!(only_operators, Err(Error::EmptySearchQuery)));
+        assert_eq!(query, "query=artist:Nirvana");
+    }
+
+    #[test]
+    fn should_deserialize_composer_works() {
+        let input = r#"
+            {
+                "id": "24f1766e-9635-4d58-a4d4-9413f9f98a4c",
+                "name": "Johann Sebastian Bach",
+                "sort-name": "Bach, Johann Sebastian",
+                "type": "Person",
+                "works": [
+                    {
+                        "id": "1f8d3b5c-2b29-4ef6-9fd4-bde9d5b0f7a2",
+                        "title": "Brandenburg Concerto no. 3 in G major, BWV 1048",
+                        "type": "Concerto",
+                        "language": null,
+                        "iswcs": []
+                    },
+                    {
+                        "id": "a9a7bfbd-e38c-3a66-8e1a-0f1b9d9a2c2b",
+                        "title": "Toccata and Fugue in D minor, BWV 565",
+                        "type": null,
+                        "language": null,
+                        "iswcs": []
+                    }
+                ]
+            }"#;
+
+        let artist: Artist = serde_json::from_str(input).unwrap();
+        let works = artist.works.unwrap();
+
+        assert_eq!(works.len(), 2);
+        assert_eq!(
+            works[0].title,
+            "Brandenburg Concerto no. 3 in G major, BWV 1048"
+        );
+
+        let mut query = Artist::fetch();
+        query
+            .id("24f1766e-9635-4d58-a4d4-9413f9f98a4c")
+            .with_works();
+        assert!(query.url().ends_with("?fmt=json&inc=works"));
+    }
+
+    const SAKAMOTO_ALIASES: &str = r#"[
+        {"name": "坂本龍一", "sort-name": "坂本龍一", "locale": "ja", "type": "Artist name",
+         "primary": true},
+        {"name": "Ryuichi Sakamoto", "sort-name": "Sakamoto, Ryuichi", "locale": "en",
+         "type": "Artist name", "primary": true}
+    ]"#;
+
+    fn sakamoto(aliases: Option<&str>) -> Artist {
+        let aliases = aliases.map_or(String::new(), |aliases| {
+            format!(r#", "aliases": {}"#, aliases)
+        });
+        serde_json::from_str(&format!(
+            r#"{{"id": "fa3b825f-7c85-4377-b393-d28a2016e293", "name": "Ryuichi Sakamoto",
+                "sort-name": "Sakamoto, Ryuichi"{}}}"#,
+            aliases
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn should_group_aliases_by_locale() {
+        let artist = sakamoto(Some(
+            r#"[
+                {"name": "坂本龍一", "sort-name": "坂本龍一", "locale": "ja", "primary": true},
+                {"name": "Sakamoto Ryuichi", "sort-name": "Sakamoto, Ryuichi", "locale": "en",
+                 "primary": false},
+                {"name": "Ryuichi Sakamoto", "sort-name": "Sakamoto, Ryuichi", "locale": "en",
+                 "primary": true},
+                {"name": "Riuichi Sakamoto", "sort-name": "Sakamoto, Riuichi",
+                 "type": "Search hint", "locale": null}
+            ]"#,
+        ));
+
+        let aliases = artist.aliases_by_locale();
+        let names = |locale: &str| -> Vec<&str> {
+            aliases[locale]
+                .iter()
+                .map(|alias| alias.name.as_str())
+                .collect()
+        };
+
+        assert_eq!(aliases.len(), 3);
+        assert_eq!(names("ja"), vec!["坂本龍一"]);
+        assert_eq!(names("en"), vec!["Ryuichi Sakamoto", "Sakamoto Ryuichi"]);
+        assert_eq!(names(Alias::NO_LOCALE), vec!["Riuichi Sakamoto"]);
+        assert!(sakamoto(None).aliases_by_locale().is_empty());
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn should_use_loaded_aliases_for_localized_name() {
+        // No request is sent: the base url is unreachable.
+        let artist = sakamoto(Some(SAKAMOTO_ALIASES));
+
+        assert_eq!(
+            localized_name("http://localhost:1", &artist, "ja_JP").unwrap(),
+            "坂本龍一"
+        );
+        assert_eq!(
+            localized_name("http://localhost:1", &artist, "de").unwrap(),
+            "Ryuichi Sakamoto"
+        );
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn should_fetch_aliases_for_localized_name() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/artist/fa3b825f-7c85-4377-b393-d28a2016e293")
+            .match_query(mockito::Matcher::UrlEncoded("inc".into(), "aliases".into()))
+            .with_body(format!(
+                r#"{{"id": "fa3b825f-7c85-4377-b393-d28a2016e293", "name": "Ryuichi Sakamoto",
+                    "sort-name": "Sakamoto, Ryuichi", "aliases": {}}}"#,
+                SAKAMOTO_ALIASES
+            ))
+            .expect(1)
+            .create();
+
+        let name = localized_name(&server.url(), &sakamoto(None), "ja").unwrap();
+
+        mock.assert();
+        assert_eq!(name, "坂本龍一");
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_fetch_aliases_for_localized_name() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/artist/fa3b825f-7c85-4377-b393-d28a2016e293")
+            .match_query(mockito::Matcher::UrlEncoded("inc".into(), "aliases".into()))
+            .with_body(format!(
+                r#"{{"id": "fa3b825f-7c85-4377-b393-d28a2016e293", "name": "Ryuichi Sakamoto",
+                    "sort-name": "Sakamoto, Ryuichi", "aliases": {}}}"#,
+                SAKAMOTO_ALIASES
+            ))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let name = localized_name(&server.url(), &sakamoto(None), "ja")
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(name, "坂本龍一");
+        let artist = sakamoto(Some(SAKAMOTO_ALIASES));
+        assert_eq!(
+            localized_name("http://localhost:1", &artist, "en")
+                .await
+                .unwrap(),
+            "Ryuichi Sakamoto"
+        );
+    }
+
+    #[test]
+    fn should_request_all_relations() {
+        let mut query = Artist::fetch();
+        query
+            .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+            .with_aliases()
+            .with_all_relations();
+
+        assert!(query.url().ends_with(
+            "?fmt=json&inc=aliases+area-rels+artist-rels+event-rels+instrument-rels+label-rels\
+             +place-rels+recording-rels+release-rels+release-group-rels+series-rels+url-rels\
+             +work-rels"
+        ));
+    }
+}
@@ -34,3 +34,5 @@ pub struct CDStubSearchQuery {
     /// the number of tracks on the CD stub
     pub tracks: u32,
 }
+
+impl_try_build!(CDStubSearchQueryLuceneQueryBuilder);
@@ -0,0 +1,390 @@
+//! A built-in table of the countries MusicBrainz knows, by
+//! [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) code, to resolve codes
+//! like the release `country` without a network call.
+//!
+//! Besides the current ISO codes, MusicBrainz uses a few codes of its own: `XE` (Europe), `XW`
+//! (worldwide), and the codes of former countries (`SU`, `YU`, `CS`, `XC`, `XG`).
+//!
+//! [`COUNTRY_AREAS`] holds the area MBIDs of the most common release countries. For the other
+//! countries, search the area by code to get it, e.g.
+//! `AreaSearchQuery::query_builder().iso1("GB")`.
+
+use crate::entity::mbid::Mbid;
+
+/// The MusicBrainz area name of each country code, sorted by code.
+pub const COUNTRIES: &[(&str, &str)] = &[
+    ("AD", "Andorra"),
+    ("AE", "United Arab Emirates"),
+    ("AF", "Afghanistan"),
+    ("AG", "Antigua and Barbuda"),
+    ("AI", "Anguilla"),
+    ("AL", "Albania"),
+    ("AM", "Armenia"),
+    ("AO", "Angola"),
+    ("AQ", "Antarctica"),
+    ("AR", "Argentina"),
+    ("AS", "American Samoa"),
+    ("AT", "Austria"),
+    ("AU", "Australia"),
+    ("AW", "Aruba"),
+    ("AX", "Åland Islands"),
+    ("AZ", "Azerbaijan"),
+    ("BA", "Bosnia and Herzegovina"),
+    ("BB", "Barbados"),
+    ("BD", "Bangladesh"),
+    ("BE", "Belgium"),
+    ("BF", "Burkina Faso"),
+    ("BG", "Bulgaria"),
+    ("BH", "Bahrain"),
+    ("BI", "Burundi"),
+    ("BJ", "Benin"),
+    ("BL", "Saint Barthélemy"),
+    ("BM", "Bermuda"),
+    ("BN", "Brunei"),
+    ("BO", "Bolivia"),
+    ("BQ", "Bonaire, Sint Eustatius and Saba"),
+    ("BR", "Brazil"),
+    ("BS", "Bahamas"),
+    ("BT", "Bhutan"),
+    ("BV", "Bouvet Island"),
+    ("BW", "Botswana"),
+    ("BY", "Belarus"),
+    ("BZ", "Belize"),
+    ("CA", "Canada"),
+    ("CC", "Cocos (Keeling) Islands"),
+    ("CD", "Democratic Republic of the Congo"),
+    ("CF", "Central African Republic"),
+    ("CG", "Congo"),
+    ("CH", "Switzerland"),
+    ("CI", "Côte d'Ivoire"),
+    ("CK", "Cook Islands"),
+    ("CL", "Chile"),
+    ("CM", "Cameroon"),
+    ("CN", "China"),
+    ("CO", "Colombia"),
+    ("CR", "Costa Rica"),
+    ("CS", "Serbia and Montenegro"),
+    ("CU", "Cuba"),
+    ("CV", "Cape Verde"),
+    ("CW", "Curaçao"),
+    ("CX", "Christmas Island"),
+    ("CY", "Cyprus"),
+    ("CZ", "Czechia"),
+    ("DE", "Germany"),
+    ("DJ", "Djibouti"),
+    ("DK", "Denmark"),
+    ("DM", "Dominica"),
+    ("DO", "Dominican Republic"),
+    ("DZ", "Algeria"),
+    ("EC", "Ecuador"),
+    ("EE", "Estonia"),
+    ("EG", "Egypt"),
+    ("EH", "Western Sahara"),
+    ("ER", "Eritrea"),
+    ("ES", "Spain"),
+    ("ET", "Ethiopia"),
+    ("FI", "Finland"),
+    ("FJ", "Fiji"),
+    ("FK", "Falkland Islands"),
+    ("FM", "Federated States of Micronesia"),
+    ("FO", "Faroe Islands"),
+    ("FR", "France"),
+    ("GA", "Gabon"),
+    ("GB", "United Kingdom"),
+    ("GD", "Grenada"),
+    ("GE", "Georgia"),
+    ("GF", "French Guiana"),
+    ("GG", "Guernsey"),
+    ("GH", "Ghana"),
+    ("GI", "Gibraltar"),
+    ("GL", "Greenland"),
+    ("GM", "Gambia"),
+    ("GN", "Guinea"),
+    ("GP", "Guadeloupe"),
+    ("GQ", "Equatorial Guinea"),
+    ("GR", "Greece"),
+    ("GS", "South Georgia and the South Sandwich Islands"),
+    ("GT", "Guatemala"),
+    ("GU", "Guam"),
+    ("GW", "Guinea-Bissau"),
+    ("GY", "Guyana"),
+    ("HK", "Hong Kong"),
+    ("HM", "Heard Island and McDonald Islands"),
+    ("HN", "Honduras"),
+    ("HR", "Croatia"),
+    ("HT", "Haiti"),
+    ("HU", "Hungary"),
+    ("ID", "Indonesia"),
+    ("IE", "Ireland"),
+    ("IL", "Israel"),
+    ("IM", "Isle of Man"),
+    ("IN", "India"),
+    ("IO", "British Indian Ocean Territory"),
+    ("IQ", "Iraq"),
+    ("IR", "Iran"),
+    ("IS", "Iceland"),
+    ("IT", "Italy"),
+    ("JE", "Jersey"),
+    ("JM", "Jamaica"),
+    ("JO", "Jordan"),
+    ("JP", "Japan"),
+    ("KE", "Kenya"),
+    ("KG", "Kyrgyzstan"),
+    ("KH", "Cambodia"),
+    ("KI", "Kiribati"),
+    ("KM", "Comoros"),
+    ("KN", "Saint Kitts and Nevis"),
+    ("KP", "North Korea"),
+    ("KR", "South Korea"),
+    ("KW", "Kuwait"),
+    ("KY", "Cayman Islands"),
+    ("KZ", "Kazakhstan"),
+    ("LA", "Laos"),
+    ("LB", "Lebanon"),
+    ("LC", "Saint Lucia"),
+    ("LI", "Liechtenstein"),
+    ("LK", "Sri Lanka"),
+    ("LR", "Liberia"),
+    ("LS", "Lesotho"),
+    ("LT", "Lithuania"),
+    ("LU", "Luxembourg"),
+    ("LV", "Latvia"),
+    ("LY", "Libya"),
+    ("MA", "Morocco"),
+    ("MC", "Monaco"),
+    ("MD", "Moldova"),
+    ("ME", "Montenegro"),
+    ("MF", "Saint Martin (French part)"),
+    ("MG", "Madagascar"),
+    ("MH", "Marshall Islands"),
+    ("MK", "North Macedonia"),
+    ("ML", "Mali"),
+    ("MM", "Myanmar"),
+    ("MN", "Mongolia"),
+    ("MO", "Macao"),
+    ("MP", "Northern Mariana Islands"),
+    ("MQ", "Martinique"),
+    ("MR", "Mauritania"),
+    ("MS", "Montserrat"),
+    ("MT", "Malta"),
+    ("MU", "Mauritius"),
+    ("MV", "Maldives"),
+    ("MW", "Malawi"),
+    ("MX", "Mexico"),
+    ("MY", "Malaysia"),
+    ("MZ", "Mozambique"),
+    ("NA", "Namibia"),
+    ("NC", "New Caledonia"),
+    ("NE", "Niger"),
+    ("NF", "Norfolk Island"),
+    ("NG", "Nigeria"),
+    ("NI", "Nicaragua"),
+    ("NL", "Netherlands"),
+    ("NO", "Norway"),
+    ("NP", "Nepal"),
+    ("NR", "Nauru"),
+    ("NU", "Niue"),
+    ("NZ", "New Zealand"),
+    ("OM", "Oman"),
+    ("PA", "Panama"),
+    ("PE", "Peru"),
+    ("PF", "French Polynesia"),
+    ("PG", "Papua New Guinea"),
+    ("PH", "Philippines"),
+    ("PK", "Pakistan"),
+    ("PL", "Poland"),
+    ("PM", "Saint Pierre and Miquelon"),
+    ("PN", "Pitcairn"),
+    ("PR", "Puerto Rico"),
+    ("PS", "Palestine"),
+    ("PT", "Portugal"),
+    ("PW", "Palau"),
+    ("PY", "Paraguay"),
+    ("QA", "Qatar"),
+    ("RE", "Réunion"),
+    ("RO", "Romania"),
+    ("RS", "Serbia"),
+    ("RU", "Russia"),
+    ("RW", "Rwanda"),
+    ("SA", "Saudi Arabia"),
+    ("SB", "Solomon Islands"),
+    ("SC", "Seychelles"),
+    ("SD", "Sudan"),
+    ("SE", "Sweden"),
+    ("SG", "Singapore"),
+    ("SH", "Saint Helena, Ascension and Tristan da Cunha"),
+    ("SI", "Slovenia"),
+    ("SJ", "Svalbard and Jan Mayen"),
+    ("SK", "Slovakia"),
+    ("SL", "Sierra Leone"),
+    ("SM", "San Marino"),
+    ("SN", "Senegal"),
+    ("SO", "Somalia"),
+    ("SR", "Suriname"),
+    ("SS", "South Sudan"),
+    ("ST", "Sao Tome and Principe"),
+    ("SU", "Soviet Union"),
+    ("SV", "El Salvador"),
+    ("SX", "Sint Maarten (Dutch part)"),
+    ("SY", "Syria"),
+    ("SZ", "Eswatini"),
+    ("TC", "Turks and Caicos Islands"),
+    ("TD", "Chad"),
+    ("TF", "French Southern Territories"),
+    ("TG", "Togo"),
+    ("TH", "Thailand"),
+    ("TJ", "Tajikistan"),
+    ("TK", "Tokelau"),
+    ("TL", "Timor-Leste"),
+    ("TM", "Turkmenistan"),
+    ("TN", "Tunisia"),
+    ("TO", "Tonga"),
+    ("TR", "Turkey"),
+    ("TT", "Trinidad and Tobago"),
+    ("TV", "Tuvalu"),
+    ("TW", "Taiwan"),
+    ("TZ", "Tanzania"),
+    ("UA", "Ukraine"),
+    ("UG", "Uganda"),
+    ("UM", "United States Minor Outlying Islands"),
+    ("US", "United States"),
+    ("UY", "Uruguay"),
+    ("UZ", "Uzbekistan"),
+    ("VA", "Vatican City"),
+    ("VC", "Saint Vincent and the Grenadines"),
+    ("VE", "Venezuela"),
+    ("VG", "British Virgin Islands"),
+    ("VI", "U.S. Virgin Islands"),
+    ("VN", "Vietnam"),
+    ("VU", "Vanuatu"),
+    ("WF", "Wallis and Futuna"),
+    ("WS", "Samoa"),
+    ("XC", "Czechoslovakia"),
+    ("XE", "Europe"),
+    ("XG", "East Germany"),
+    ("XW", "[Worldwide]"),
+    ("YE", "Yemen"),
+    ("YT", "Mayotte"),
+    ("YU", "Yugoslavia"),
+    ("ZA", "South Africa"),
+    ("ZM", "Zambia"),
+    ("ZW", "Zimbabwe"),
+];
+
+/// The MusicBrainz area MBID of the most common release countries, sorted by code.
+pub const COUNTRY_AREAS: &[(&str, &str)] = &[
+    ("AU", "106e0bec-b638-3b37-b731-f53d507dc00e"),
+    ("CA", "71bbafaa-e825-3e15-8ca9-017dcad1748b"),
+    ("DE", "85752fda-13c4-31a3-bee5-0e5cb1f51dad"),
+    ("ES", "471c46a7-afc5-31c4-923c-d0444f5053a4"),
+    ("FR", "08310658-51eb-3801-80de-5a0739207115"),
+    ("GB", "8a754a16-0027-3a29-b6d7-2b40ea0481ed"),
+    ("IT", "c6500277-9a3d-349b-bf30-41afdbf42add"),
+    ("JP", "2db42837-c832-3c27-b4a3-08198f75693c"),
+    ("NL", "ef1b7cc0-cd26-36f4-8ea0-04d9623786c7"),
+    ("SE", "23d10872-f5ae-3f0c-bf55-332788a16ecb"),
+    ("US", "489ce91b-6658-3307-9877-795b68554c98"),
+    ("XE", "89a675c2-3e37-3518-b83c-418bad59a85a"),
+    ("XW", "525d4e18-3d00-31b9-a58b-a146a916de8f"),
+];
+
+/// Get the code used in [`COUNTRIES`] for `code`, ignoring case. `UK`, which is not the ISO code
+/// of the United Kingdom but is commonly used for it, maps to `GB`.
+pub fn canonical_country_code(code: &str) -> Option<&'static str> {
+    country(code).map(|(code, _)| code)
+}
+
+/// Get the MusicBrainz area name of the country with the given code, ignoring case.
+///
+/// ## Example
+/// ```rust
+/// use musicbrainz_rs_nova::entity::country::country_name;
+///
+/// assert_eq!(country_name("gb"), Some("United Kingdom"));
+/// assert_eq!(country_name("UK"), Some("United Kingdom"));
+/// assert_eq!(country_name("XW"), Some("[Worldwide]"));
+/// assert_eq!(country_name("ZZ"), None);
+/// ```
+pub fn country_name(code: &str) -> Option<&'static str> {
+    country(code).map(|(_, name)| name)
+}
+
+/// Get the MusicBrainz area MBID of the country with the given code, ignoring case, without a
+/// network call. `None` for an unknown code, or a country missing from [`COUNTRY_AREAS`].
+///
+/// ## Example
+/// ```rust
+/// use musicbrainz_rs_nova::entity::country::country_area_mbid;
+///
+/// let united_kingdom = country_area_mbid("UK").unwrap();
+/// assert_eq!(united_kingdom, "8a754a16-0027-3a29-b6d7-2b40ea0481ed");
+/// ```
+pub fn country_area_mbid(code: &str) -> Option<Mbid> {
+    let code = canonical_country_code(code)?;
+
+    COUNTRY_AREAS
+        .binary_search_by(|(candidate, _)| (*candidate).cmp(code))
+        .ok()
+        .map(|index| COUNTRY_AREAS[index].1.parse().expect("valid MBID"))
+}
+
+fn country(code: &str) -> Option<(&'static str, &'static str)> {
+    let code = code.trim().to_ascii_uppercase();
+    let code = if code == "UK" { "GB" } else { code.as_str() };
+
+    COUNTRIES
+        .binary_search_by(|(candidate, _)| (*candidate).cmp(code))
+        .ok()
+        .map(|index| COUNTRIES[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_keep_countries_sorted() {
+        assert!(COUNTRIES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(COUNTRY_AREAS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn should_resolve_country_area_mbids() {
+        assert!(COUNTRY_AREAS
+            .iter()
+            .all(|(code, mbid)| country_name(code).is_some() && Mbid::is_valid(mbid)));
+
+        let united_kingdom = country_area_mbid("GB").unwrap();
+        assert_eq!(united_kingdom, "8a754a16-0027-3a29-b6d7-2b40ea0481ed");
+        assert_eq!(country_area_mbid("uk"), Some(united_kingdom));
+        assert_eq!(
+            country_area_mbid(" us "),
+            "489ce91b-6658-3307-9877-795b68554c98".parse().ok()
+        );
+        assert_eq!(
+            country_area_mbid("XW"),
+            "525d4e18-3d00-31b9-a58b-a146a916de8f".parse().ok()
+        );
+        assert_eq!(country_area_mbid("ZW"), None);
+        assert_eq!(country_area_mbid("ZZ"), None);
+    }
+
+    #[test]
+    fn should_resolve_country_names() {
+        assert_eq!(country_name("US"), Some("United States"));
+        assert_eq!(country_name("de"), Some("Germany"));
+        assert_eq!(country_name(" JP "), Some("Japan"));
+        assert_eq!(country_name("XE"), Some("Europe"));
+        assert_eq!(country_name("SU"), Some("Soviet Union"));
+        assert_eq!(country_name(""), None);
+        assert_eq!(country_name("GBR"), None);
+    }
+
+    #[test]
+    fn should_map_uk_to_gb() {
+        assert_eq!(canonical_country_code("UK"), Some("GB"));
+        assert_eq!(canonical_country_code("gb"), Some("GB"));
+        assert_eq!(country_name("UK"), country_name("GB"));
+        assert_eq!(canonical_country_code("EU"), None);
+    }
+}
@@ -1,4 +1,5 @@
-use serde::{Serialize, Deserialize};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// Disc ID is the code number which MusicBrainz uses to link a physical CD to a release listing.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
@@ -7,6 +8,68 @@ pub struct Disc {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
     pub id: String,
     pub offset_count: u32,
+    /// The total number of sectors of the disc, i.e. the offset of the lead-out.
     pub sectors: u32,
-    pub offsets : Vec<u32>
+    /// The offset of each track in the table of contents, in sectors.
+    pub offsets: Vec<u32>,
+}
+
+impl Disc {
+    /// The number of sectors read per second on an audio CD, per the Red Book standard.
+    pub const SECTORS_PER_SECOND: u32 = 75;
+
+    /// The playing time of `sectors` sectors.
+    pub fn sectors_to_duration(sectors: u32) -> Duration {
+        Duration::from_nanos(
+            u64::from(sectors) * 1_000_000_000 / u64::from(Self::SECTORS_PER_SECOND),
+        )
+    }
+
+    /// The length of each track, computed from the table of contents: the sectors between a
+    /// track offset and the next one, or the lead-out for the last track.
+    pub fn track_lengths(&self) -> Vec<Duration> {
+        self.offsets
+            .iter()
+            .zip(
+                self.offsets
+                    .iter()
+                    .skip(1)
+                    .chain(std::iter::once(&self.sectors)),
+            )
+            .map(|(start, end)| Self::sectors_to_duration(end.saturating_sub(*start)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_convert_sectors_to_duration() {
+        assert_eq!(Disc::sectors_to_duration(75), Duration::from_secs(1));
+        assert_eq!(Disc::sectors_to_duration(150), Duration::from_secs(2));
+        assert_eq!(Disc::sectors_to_duration(1).as_micros(), 13_333);
+    }
+
+    #[test]
+    fn should_compute_track_lengths_from_offsets() {
+        let disc: Disc = serde_json::from_str(
+            r#"{
+                "id": "I5l9cCSFccLKFEKS.7wqSZAorPU-",
+                "offset-count": 3,
+                "sectors": 58317,
+                "offsets": [150, 22767, 41887]
+            }"#,
+        )
+        .unwrap();
+
+        let lengths: Vec<u128> = disc
+            .track_lengths()
+            .iter()
+            .map(Duration::as_millis)
+            .collect();
+
+        assert_eq!(lengths, [301_560, 254_933, 219_066]);
+    }
 }
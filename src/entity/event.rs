@@ -1,11 +1,16 @@
 use super::{Include, Relationship, Subquery};
 use crate::entity::alias::Alias;
+use crate::entity::artist::Artist;
 use crate::entity::genre::Genre;
 use crate::entity::lifespan::LifeSpan;
+use crate::entity::mbid::Mbid;
+use crate::entity::place::Place;
 use crate::entity::rating::Rating;
-use crate::entity::relations::Relation;
+use crate::entity::relations::{Relation, RelationContent};
 use crate::entity::tag::Tag;
 use crate::entity::BrowseBy;
+#[cfg(any(feature = "blocking", feature = "async"))]
+use crate::{Error, Fetch};
 use serde::{Deserialize, Serialize};
 
 use chrono::NaiveDate;
@@ -17,6 +22,7 @@ use lucene_query_builder::QueryBuilder;
 /// Variants are derived from the `event_type` table in the MusicBrainz database.
 #[non_exhaustive]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "tracing", serde(remote = "Self"))]
 pub enum EventType {
     /// An individual concert by a single artist or collaboration, often with supporting artists
     /// who perform before the main act.
@@ -42,26 +48,53 @@ pub enum EventType {
     /// audience members' playing skills.
     #[serde(rename = "Masterclass/Clinic")]
     MasterclassClinic,
+    /// An event where awards are given to musicians and other people in the music industry.
+    #[serde(rename = "Award ceremony")]
+    AwardCeremony,
+    /// An event where artists compete against each other, such as a song contest.
+    Competition,
+    /// An event focused on dancing to music, such as a rave or a club night.
+    Party,
     /// Any event_type that does not yet have a corresponding variant in this enum.
     /// If you ever see a `EventType::UnrecognizedEventType` in the wild, let us know and file an issue/pull request!
     #[serde(other)]
     UnrecognizedEventType,
 }
 
+impl EventType {
+    /// The name MusicBrainz gives to the type, as used by the search API `type` field.
+    /// `UnrecognizedEventType` maps to an empty string since the original value is not kept.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventType::Concert => "Concert",
+            EventType::Festival => "Festival",
+            EventType::StagePerformance => "Stage performance",
+            EventType::LaunchEvent => "Launch event",
+            EventType::ConventionExpo => "Convention/Expo",
+            EventType::MasterclassClinic => "Masterclass/Clinic",
+            EventType::AwardCeremony => "Award ceremony",
+            EventType::Competition => "Competition",
+            EventType::Party => "Party",
+            EventType::UnrecognizedEventType => "",
+        }
+    }
+}
+
 /// An event refers to an organised event which people can attend, and is relevant to MusicBrainz.
 /// Generally this means live performances, like concerts and festivals.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 #[serde(rename_all(deserialize = "kebab-case"))]
 pub struct Event {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
 
     /// The name is the official name of the event if it has one, or a descriptive name (like
     /// "Main Artist at Place") if not.
     pub name: String,
 
     /// The type describes what kind of event the event is. The possible values are: Concert,
-    /// Festival, Launch event, Convention/Expo, Masterclass/Clinic
+    /// Festival, Stage performance, Launch event, Convention/Expo, Masterclass/Clinic, Award
+    /// ceremony, Competition, Party.
     #[serde(rename = "type")]
     pub event_type: Option<EventType>,
     /// The cancelled field describes whether or not the event took place.
@@ -81,6 +114,8 @@ pub struct Event {
     pub relations: Option<Vec<Relation>>,
 
     pub rating: Option<Rating>,
+    /// The rating the authenticated user gave, loaded with the `user-ratings` include.
+    pub user_rating: Option<Rating>,
     /// Aliases are alternate names for an event, which currently have two main functions: localised
     /// names and search hints. Localised names are used to store the official names used in different
     /// languages and countries. These use the locale field to identify which language or country the
@@ -98,26 +133,26 @@ pub struct Event {
     /// The begin and end dates indicate when an artist started and finished its existence.
     /// Its exact meaning depends on the type of artist:
     ///
-    ///  - For a person
-    ///        Begin date represents date of birth, and end date represents date of death.
+    /// - For a person:
+    ///   Begin date represents date of birth, and end date represents date of death.
     ///
-    ///    - For a group (or orchestra/choir)
-    ///        Begin date represents the date when the group first formed: if a group dissolved and then
-    ///        reunited, the date is still that of when they first formed. End date represents the date
-    ///        when the group last dissolved: if a group dissolved and then reunited, the date is that
-    ///        of when they last dissolved (if they are together, it should be blank!). For listing
-    ///        other inactivity periods, just use the annotation and the "member of" relationships.
+    /// - For a group (or orchestra/choir):
+    ///   Begin date represents the date when the group first formed: if a group dissolved and then
+    ///   reunited, the date is still that of when they first formed. End date represents the date
+    ///   when the group last dissolved: if a group dissolved and then reunited, the date is that
+    ///   of when they last dissolved (if they are together, it should be blank!). For listing
+    ///   other inactivity periods, just use the annotation and the "member of" relationships.
     ///
-    ///    - For a character
-    ///        Begin date represents the date (in real life) when the character concept was created.
-    ///        The End date should not be set, since new media featuring a character can be created
-    ///        at any time. In particular, the Begin and End date fields should not be used to hold
-    ///        the fictional birth or death dates of a character.
-    ///        (This information can be put in the annotation.)
+    /// - For a character:
+    ///   Begin date represents the date (in real life) when the character concept was created.
+    ///   The End date should not be set, since new media featuring a character can be created
+    ///   at any time. In particular, the Begin and End date fields should not be used to hold
+    ///   the fictional birth or death dates of a character.
+    ///   (This information can be put in the annotation.)
     ///
-    ///    - For others
-    ///        There are no clear indications about how to use dates for artists of the type Other at
-    ///        the moment.
+    /// - For others:
+    ///   There are no clear indications about how to use dates for artists of the type Other at
+    ///   the moment.
     pub life_span: Option<LifeSpan>,
 
     /// The disambiguation comments are fields in the database used to help distinguish identically
@@ -128,6 +163,79 @@ pub struct Event {
     pub type_id: Option<String>,
 }
 
+/// The event-artist relationship types of the artists performing at an event.
+const PERFORMER_RELATIONS: &[&str] = &["main performer", "support act", "guest performer"];
+
+impl Event {
+    /// The artists performing at the event: main performers, support acts and guest performers,
+    /// in the order of the relations. Requires the event to be fetched with
+    /// [`with_artist_relations`](crate::FetchQuery::with_artist_relations).
+    ///
+    /// The artists are the short versions embedded in the relations, see
+    /// [`Event::fetch_performers`] to get the full entities.
+    pub fn performers(&self) -> Vec<&Artist> {
+        self.relations
+            .iter()
+            .flatten()
+            .filter(|relation| PERFORMER_RELATIONS.contains(&relation.relation_type.as_str()))
+            .filter_map(|relation| match &relation.content {
+                RelationContent::Artist(artist) => Some(artist.as_ref()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The place the event was "held at". Requires the event to be fetched with
+    /// [`with_place_relations`](crate::FetchQuery::with_place_relations).
+    pub fn venue(&self) -> Option<&Place> {
+        self.relations
+            .iter()
+            .flatten()
+            .filter(|relation| relation.relation_type == "held at")
+            .find_map(|relation| match &relation.content {
+                RelationContent::Place(place) => Some(place.as_ref()),
+                _ => None,
+            })
+    }
+
+    /// Fetch the full [`performers`](Event::performers) of the event, one request each.
+    #[cfg(feature = "blocking")]
+    pub fn fetch_performers(&self) -> Result<Vec<Artist>, Error> {
+        self.performers()
+            .into_iter()
+            .map(|artist| Artist::fetch().id(&artist.id).execute())
+            .collect()
+    }
+
+    /// Fetch the full [`performers`](Event::performers) of the event, one request each.
+    #[cfg(feature = "async")]
+    pub async fn fetch_performers(&self) -> Result<Vec<Artist>, Error> {
+        let mut performers = vec![];
+        for artist in self.performers() {
+            performers.push(Artist::fetch().id(&artist.id).execute().await?);
+        }
+        Ok(performers)
+    }
+
+    /// Fetch the full [`venue`](Event::venue) of the event.
+    #[cfg(feature = "blocking")]
+    pub fn fetch_venue(&self) -> Result<Option<Place>, Error> {
+        match self.venue() {
+            Some(place) => Place::fetch().id(&place.id).execute().map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetch the full [`venue`](Event::venue) of the event.
+    #[cfg(feature = "async")]
+    pub async fn fetch_venue(&self) -> Result<Option<Place>, Error> {
+        match self.venue() {
+            Some(place) => Place::fetch().id(&place.id).execute().await.map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize, QueryBuilder)]
 pub struct EventSearchQuery {
     /// (part of) any alias attached to the artist (diacritics are ignored)
@@ -142,7 +250,7 @@ pub struct EventSearchQuery {
     pub artist: String,
     /// the event's begin date (e.g. "1980-01-22")
     pub begin: Option<NaiveDate>,
-    /// (part of) the artist's disambiguation comment
+    /// (part of) the event's disambiguation comment
     pub comment: String,
     /// the event's end date (e.g. "1980-01-22")
     pub end: Option<NaiveDate>,
@@ -166,6 +274,21 @@ pub struct EventSearchQuery {
     pub event_type: String,
 }
 
+impl_try_build!(EventSearchQueryLuceneQueryBuilder);
+
+impl EventSearchQueryLuceneQueryBuilder {
+    /// Typed version of the `event_type` setter.
+    pub fn event_type_typed(&mut self, event_type: EventType) -> &mut Self {
+        let value = event_type.as_str();
+        // A `/` starts a regular expression in Lucene, so those types are searched as a phrase.
+        if value.contains('/') {
+            self.event_type(&format!("\"{}\"", value))
+        } else {
+            self.event_type(value)
+        }
+    }
+}
+
 impl_browse! {
 Event,
    (by_area, BrowseBy::Area),
@@ -192,6 +315,136 @@ impl_includes!(
     (with_tags, Include::Subquery(Subquery::Tags)),
     (with_aliases, Include::Subquery(Subquery::Aliases)),
     (with_ratings, Include::Subquery(Subquery::Rating)),
+    (with_user_ratings, Include::Subquery(Subquery::UserRatings)),
     (with_genres, Include::Subquery(Subquery::Genres)),
     (with_annotations, Include::Subquery(Subquery::Annotations))
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_read_performers_and_venue() {
+        let json = r#"{
+            "id": "e921686d-ba86-4122-bc3b-777aec90d231",
+            "name": "Nirvana at Reading Festival 1992",
+            "time": "",
+            "relations": [
+                {
+                    "type": "main performer",
+                    "type-id": "936c7c95-3156-3889-a062-8a0cd57f8946",
+                    "direction": "backward",
+                    "target-type": "artist",
+                    "artist": {
+                        "id": "5b11f4ce-a62d-471e-81fc-a69a8278c7da",
+                        "name": "Nirvana",
+                        "sort-name": "Nirvana",
+                        "disambiguation": "90s US grunge band"
+                    }
+                },
+                {
+                    "type": "support act",
+                    "type-id": "492a850e-97eb-306a-a85e-4b6d98527796",
+                    "direction": "backward",
+                    "target-type": "artist",
+                    "artist": {
+                        "id": "e1abc357-fe2f-4b44-9c13-1e2e4d4db4b1",
+                        "name": "Mudhoney",
+                        "sort-name": "Mudhoney",
+                        "disambiguation": ""
+                    }
+                },
+                {
+                    "type": "held at",
+                    "type-id": "e2c6f697-07dc-38b1-be0b-83d740165532",
+                    "direction": "forward",
+                    "target-type": "place",
+                    "place": {
+                        "id": "f1b3bff2-5dc6-4f2e-9aa1-9d5fa9da4d62",
+                        "name": "Little John's Farm",
+                        "disambiguation": ""
+                    }
+                },
+                {
+                    "type": "poster",
+                    "type-id": "e3d4b8a9-01fb-4f51-8e43-a2055c5bba6c",
+                    "direction": "forward",
+                    "target-type": "url",
+                    "url": {
+                        "id": "1e3fb7a0-3b4e-4a0f-8b6c-5c2d7e1c9a55",
+                        "resource": "https://example.com/poster.jpg"
+                    }
+                }
+            ]
+        }"#;
+
+        let event: Event = serde_json::from_str(json).unwrap();
+        let performers: Vec<&str> = event
+            .performers()
+            .iter()
+            .map(|artist| artist.name.as_str())
+            .collect();
+
+        assert_eq!(performers, vec!["Nirvana", "Mudhoney"]);
+        assert_eq!(event.venue().unwrap().name, "Little John's Farm");
+    }
+
+    #[test]
+    fn should_have_no_performers_without_relations() {
+        let event: Event = serde_json::from_str(
+            r#"{"id": "e921686d-ba86-4122-bc3b-777aec90d231", "name": "Reading 1992", "time": ""}"#,
+        )
+        .unwrap();
+
+        assert!(event.performers().is_empty());
+        assert_eq!(event.venue(), None);
+    }
+
+    #[test]
+    fn should_deserialize_festival_event() {
+        let json = r#"{
+            "id": "73df2f48-383b-4930-bad3-05ba938be578",
+            "name": "Reading Festival 1992",
+            "type": "Festival",
+            "type-id": "b6ded574-b592-3f0e-b56e-5b5f06aa0678",
+            "cancelled": false,
+            "time": "",
+            "setlist": "",
+            "disambiguation": "",
+            "life-span": {"begin": "1992-08-28", "end": "1992-08-30", "ended": true}
+        }"#;
+
+        let event: Event = serde_json::from_str(json).unwrap();
+
+        assert_eq!(event.event_type, Some(EventType::Festival));
+        assert_eq!(
+            event.type_id.as_deref(),
+            Some("b6ded574-b592-3f0e-b56e-5b5f06aa0678")
+        );
+    }
+
+    #[test]
+    fn should_build_typed_event_type_query() {
+        let festival = EventSearchQuery::query_builder()
+            .event_type_typed(EventType::Festival)
+            .build();
+        let launch = EventSearchQuery::query_builder()
+            .event_type_typed(EventType::LaunchEvent)
+            .build();
+        let expo = EventSearchQuery::query_builder()
+            .event_type_typed(EventType::ConventionExpo)
+            .build();
+
+        assert_eq!(festival, "query=type:Festival");
+        assert_eq!(launch, r#"query=type:"Launch event""#);
+        assert_eq!(expo, r#"query=type:"Convention/Expo""#);
+    }
+
+    #[test]
+    fn should_deserialize_unknown_event_type() {
+        let event_type: EventType = serde_json::from_str(r#""Auction""#).unwrap();
+
+        assert_eq!(event_type, EventType::UnrecognizedEventType);
+    }
+}
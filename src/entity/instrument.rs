@@ -1,6 +1,7 @@
 use super::{Include, Relationship, Subquery};
 use crate::entity::alias::Alias;
 use crate::entity::genre::Genre;
+use crate::entity::mbid::Mbid;
 use crate::entity::relations::Relation;
 use crate::entity::tag::Tag;
 use crate::entity::BrowseBy;
@@ -17,7 +18,7 @@ use lucene_query_builder::QueryBuilder;
 #[serde(rename_all(deserialize = "kebab-case"))]
 pub struct Instrument {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
     /// The instrument name is the name of the instrument, typically the most common name in English.
     pub name: String,
     /// The type categorises the instrument by the way the sound is created, similar to the
@@ -64,12 +65,15 @@ pub struct InstrumentSearchQuery {
     pub instrument_type: String,
 }
 
+impl_try_build!(InstrumentSearchQueryLuceneQueryBuilder);
+
 /// The type of a MusicBrainz instrument entity.
 /// Note that this enum is `non_exhaustive`; The list of instrument types is subject to change and
 /// these changes are only reflected in the DB, not in actual MB code.
 /// Variants are derived from the `instrument_type` table in the MusicBrainz database.
 #[non_exhaustive]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "tracing", serde(remote = "Self"))]
 pub enum InstrumentType {
     /// An aerophone, i.e. an instrument where the sound is created by vibrating air. The instrument
     /// itself does not vibrate.
@@ -1,6 +1,7 @@
 use super::{Include, Relationship, Subquery};
 use crate::entity::alias::Alias;
 use crate::entity::genre::Genre;
+use crate::entity::mbid::Mbid;
 use crate::entity::rating::Rating;
 use crate::entity::relations::Relation;
 use crate::entity::release::Release;
@@ -18,7 +19,7 @@ use lucene_query_builder::QueryBuilder;
 #[serde(rename_all(deserialize = "kebab-case"))]
 pub struct Label {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
     pub type_id: Option<String>,
     /// The type describes the main activity of the label.
     #[serde(rename = "type")]
@@ -38,6 +39,8 @@ pub struct Label {
     pub aliases: Option<Vec<Alias>>,
     pub tags: Option<Vec<Tag>>,
     pub rating: Option<Rating>,
+    /// The rating the authenticated user gave, loaded with the `user-ratings` include.
+    pub user_rating: Option<Rating>,
     pub genres: Option<Vec<Genre>>,
     /// Annotations are text fields, functioning like a miniature wiki, that can be added to any
     /// existing artists, labels, recordings, releases, release groups and works.
@@ -85,6 +88,8 @@ pub struct LabelSearchQuery {
     pub label_type: Option<LabelType>,
 }
 
+impl_try_build!(LabelSearchQueryLuceneQueryBuilder);
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 #[serde(rename_all(deserialize = "kebab-case"))]
 pub struct LabelInfo {
@@ -98,6 +103,7 @@ pub struct LabelInfo {
 /// Variants are derived from the `label_type` table in the MusicBrainz database.
 #[non_exhaustive]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "tracing", serde(remote = "Self"))]
 pub enum LabelType {
     #[serde(rename = "Bootleg Production")]
     BootlegProduction,
@@ -149,6 +155,7 @@ impl_includes!(
     (with_tags, Include::Subquery(Subquery::Tags)),
     (with_aliases, Include::Subquery(Subquery::Aliases)),
     (with_ratings, Include::Subquery(Subquery::Rating)),
+    (with_user_ratings, Include::Subquery(Subquery::UserRatings)),
     (with_genres, Include::Subquery(Subquery::Genres)),
     (with_annotations, Include::Subquery(Subquery::Annotations))
 );
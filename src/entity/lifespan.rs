@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Default)]
 #[serde(default)]
 pub struct LifeSpan {
+    /// Whether the entity has ended (e.g. a band that split up): `Some(false)` means it is known
+    /// to still be active, `None` that it is unknown.
     pub ended: Option<bool>,
     #[serde(default)]
     #[serde(deserialize_with = "date_format::deserialize_opt")]
@@ -13,3 +15,86 @@ pub struct LifeSpan {
     #[serde(deserialize_with = "date_format::deserialize_opt")]
     pub end: Option<NaiveDate>,
 }
+
+impl LifeSpan {
+    /// Returns `true` unless the entity is known to have ended. An unknown `ended` state counts
+    /// as active, since MusicBrainz only sets it once the end of the entity is known.
+    pub fn is_active(&self) -> bool {
+        self.ended != Some(true)
+    }
+}
+
+/// Implemented by the entities with a life span: areas, artists, events and places.
+pub trait Lifespanned {
+    /// The period during which the entity existed, if MusicBrainz sent one.
+    fn life_span(&self) -> Option<&LifeSpan>;
+
+    /// Returns `true` unless the entity is known to have ended, e.g. to list currently active
+    /// bands. Entities without a life span, or whose life span doesn't say if they ended, count
+    /// as active. See [`LifeSpan::is_active`].
+    fn is_active(&self) -> bool {
+        self.life_span().map_or(true, LifeSpan::is_active)
+    }
+}
+
+macro_rules! impl_lifespanned {
+    ($($t: ty), +) => {
+        $(impl Lifespanned for $t {
+            fn life_span(&self) -> Option<&LifeSpan> {
+                self.life_span.as_ref()
+            }
+        })+
+    }
+}
+
+impl_lifespanned!(
+    crate::entity::area::Area,
+    crate::entity::artist::Artist,
+    crate::entity::event::Event,
+    crate::entity::place::Place
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::artist::Artist;
+
+    #[test]
+    fn should_distinguish_ended_states() {
+        let ended: LifeSpan =
+            serde_json::from_str(r#"{"begin": "1987", "end": "1994-04-05", "ended": true}"#)
+                .unwrap();
+        let active: LifeSpan =
+            serde_json::from_str(r#"{"begin": "1994", "end": null, "ended": false}"#).unwrap();
+        let unknown: LifeSpan = serde_json::from_str(r#"{"begin": "1970"}"#).unwrap();
+        let null: LifeSpan = serde_json::from_str(r#"{"ended": null}"#).unwrap();
+
+        assert_eq!(ended.ended, Some(true));
+        assert_eq!(active.ended, Some(false));
+        assert_eq!(unknown.ended, None);
+        assert_eq!(null.ended, None);
+    }
+
+    #[test]
+    fn should_tell_active_entities() {
+        let artist = |life_span: &str| -> Artist {
+            serde_json::from_str(&format!(
+                r#"{{"id": "5b11f4ce-a62d-471e-81fc-a69a8278c7da", "name": "Nirvana",
+                    "sort-name": "Nirvana"{}}}"#,
+                life_span
+            ))
+            .unwrap()
+        };
+
+        let ended =
+            artist(r#", "life-span": {"begin": "1987", "end": "1994-04-05", "ended": true}"#);
+        let active = artist(r#", "life-span": {"begin": "1994", "ended": false}"#);
+        let unknown = artist(r#", "life-span": {"begin": "1987"}"#);
+        let missing = artist("");
+
+        assert!(!ended.is_active());
+        assert!(active.is_active());
+        assert!(unknown.is_active());
+        assert!(missing.is_active());
+    }
+}
@@ -0,0 +1,185 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+/// A [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier): the UUID
+/// identifying an entity.
+///
+/// Parsing an `Mbid` checks it is a well-formed UUID and normalizes it to lowercase.
+/// Deserialization is lenient: ids coming from MusicBrainz are trusted and kept as is.
+///
+/// ## Example
+/// ```rust
+/// use musicbrainz_rs_nova::entity::mbid::Mbid;
+///
+/// let nirvana: Mbid = "5B11F4CE-A62D-471E-81FC-A69A8278C7DA".parse().unwrap();
+/// assert_eq!(nirvana, "5b11f4ce-a62d-471e-81fc-a69a8278c7da");
+/// assert!("nirvana".parse::<Mbid>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Mbid(String);
+
+impl Mbid {
+    /// Returns `true` if `id` is a well-formed UUID, in any case.
+    pub fn is_valid(id: &str) -> bool {
+        let bytes = id.as_bytes();
+        bytes.len() == 36
+            && bytes.iter().enumerate().all(|(i, c)| match i {
+                8 | 13 | 18 | 23 => *c == b'-',
+                _ => c.is_ascii_hexdigit(),
+            })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// The error returned when parsing a malformed [`Mbid`]. It holds the rejected string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidMbid(pub String);
+
+impl fmt::Display for InvalidMbid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid MBID: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidMbid {}
+
+impl FromStr for Mbid {
+    type Err = InvalidMbid;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if Mbid::is_valid(s) {
+            Ok(Mbid(s.to_ascii_lowercase()))
+        } else {
+            Err(InvalidMbid(s.to_string()))
+        }
+    }
+}
+
+impl fmt::Display for Mbid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Deref for Mbid {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Mbid {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<Mbid> for String {
+    fn from(mbid: Mbid) -> Self {
+        mbid.0
+    }
+}
+
+impl PartialEq<str> for Mbid {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Mbid {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<String> for Mbid {
+    fn eq(&self, other: &String) -> bool {
+        &self.0 == other
+    }
+}
+
+/// Implemented by the entities identified by an [`Mbid`].
+pub trait Identified {
+    /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
+    fn mbid(&self) -> &Mbid;
+}
+
+macro_rules! impl_identified {
+    ($($t: ty), +) => {
+        $(impl Identified for $t {
+            fn mbid(&self) -> &Mbid {
+                &self.id
+            }
+        })+
+    }
+}
+
+impl_identified!(
+    crate::entity::area::Area,
+    crate::entity::artist::Artist,
+    crate::entity::event::Event,
+    crate::entity::instrument::Instrument,
+    crate::entity::label::Label,
+    crate::entity::place::Place,
+    crate::entity::recording::Recording,
+    crate::entity::release::Release,
+    crate::entity::release::Track,
+    crate::entity::release_group::ReleaseGroup,
+    crate::entity::series::Series,
+    crate::entity::url::Url,
+    crate::entity::work::Work
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn should_parse_valid_mbid() {
+        let mbid: Mbid = "5b11f4ce-a62d-471e-81fc-a69a8278c7da".parse().unwrap();
+
+        assert_eq!(mbid.as_str(), "5b11f4ce-a62d-471e-81fc-a69a8278c7da");
+        assert_eq!(mbid.to_string(), "5b11f4ce-a62d-471e-81fc-a69a8278c7da");
+
+        let uppercase: Mbid = "5B11F4CE-A62D-471E-81FC-A69A8278C7DA".parse().unwrap();
+        assert_eq!(uppercase, mbid);
+    }
+
+    #[test]
+    fn should_reject_invalid_mbid() {
+        for id in [
+            "",
+            "nirvana",
+            "5b11f4ce-a62d-471e-81fc-a69a8278c7d",
+            "5b11f4ce-a62d-471e-81fc-a69a8278c7da0",
+            "5b11f4cea62d-471e-81fc-a69a8278c7da0",
+            "5b11f4ce-a62d-471e-81fc-a69a8278c7dz",
+        ] {
+            assert_eq!(id.parse::<Mbid>(), Err(InvalidMbid(id.to_string())));
+        }
+    }
+
+    #[test]
+    fn should_deserialize_leniently() {
+        let mbid: Mbid = serde_json::from_str(r#""not-a-uuid""#).unwrap();
+
+        assert_eq!(mbid, "not-a-uuid");
+    }
+
+    #[test]
+    fn should_be_usable_as_map_key() {
+        let mbid: Mbid = "5b11f4ce-a62d-471e-81fc-a69a8278c7da".parse().unwrap();
+        let mut names = HashMap::new();
+        names.insert(mbid.clone(), "Nirvana");
+
+        assert_eq!(names.get(&mbid), Some(&"Nirvana"));
+    }
+}
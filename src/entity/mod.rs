@@ -9,11 +9,13 @@ use crate::entity::coverart::Coverart;
 use crate::entity::event::Event;
 use crate::entity::instrument::*;
 use crate::entity::label::Label;
+use crate::entity::mbid::{Identified, Mbid};
 use crate::entity::place::Place;
 use crate::entity::recording::Recording;
 use crate::entity::release::Release;
 use crate::entity::release_group::ReleaseGroup;
 use crate::entity::series::Series;
+use crate::entity::tag::Tag;
 use crate::entity::url::Url;
 use crate::entity::work::Work;
 use crate::Fetch;
@@ -21,6 +23,7 @@ use crate::Path;
 use crate::{Browse, Search};
 use crate::{CoverartQuery, FetchCoverart, FetchCoverartQuery};
 use serde::Serialize;
+use std::collections::HashSet;
 
 macro_rules! impl_includes {
     ($ty: ty, $(($args:ident, $inc: expr)),+) => {
@@ -30,6 +33,14 @@ macro_rules! impl_includes {
                      self.0.include = self.0.include($inc).include.to_owned();
                    self
                })*
+
+               /// Request all the relationship includes valid for this entity at once.
+               pub fn with_all_relations(&mut self) -> &mut Self  {
+                   for inc in $crate::entity::all_relations([$($inc),*]) {
+                       self.0.include(inc);
+                   }
+                   self
+               }
             }
 
         impl BrowseQuery<$ty> {
@@ -37,13 +48,29 @@ macro_rules! impl_includes {
                      self.inner.include = self.inner.include($inc).include.to_owned();
                    self
                })*
+
+               /// Request all the relationship includes valid for this entity at once.
+               pub fn with_all_relations(&mut self) -> &mut Self  {
+                   for inc in $crate::entity::all_relations([$($inc),*]) {
+                       self.inner.include(inc);
+                   }
+                   self
+               }
             }
 
         impl SearchQuery<$ty> {
                $(pub fn $args(&mut self) -> &mut Self  {
-                     self.0.include = self.0.include($inc).include.to_owned();
+                     self.inner.include = self.inner.include($inc).include.to_owned();
                    self
                })*
+
+               /// Request all the relationship includes valid for this entity at once.
+               pub fn with_all_relations(&mut self) -> &mut Self  {
+                   for inc in $crate::entity::all_relations([$($inc),*]) {
+                       self.inner.include(inc);
+                   }
+                   self
+               }
             }
         }
 }
@@ -53,7 +80,7 @@ macro_rules! impl_browse {
         impl BrowseQuery<$ty> {
                $(pub fn $args(&mut self, id: &str) -> &mut Self  {
                     use std::fmt::Write as _;
-                    self.inner.path.push_str(crate::config::FMT_JSON);
+                    self.inner.start_params();
                     let _ = write!(self.inner.path, "&{}={}", $browse.as_str(), id);
                     self
                })*
@@ -72,6 +99,8 @@ macro_rules! impl_fetchcoverart {
                         img_type: None,
                         img_res: None,
                     },
+                    invalid_id: None,
+                    user_agent: None,
                 });
                 coverart_query.id(&self.id);
                 coverart_query
@@ -80,18 +109,196 @@ macro_rules! impl_fetchcoverart {
     }
 }
 
+macro_rules! impl_from_response {
+    ($($t: ty), +) => {
+        $(impl $t {
+            /// Deserialize the entity from a JSON value, as returned by the MusicBrainz API.
+            pub fn from_json_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+                serde_json::from_value(value)
+            }
+
+            /// Deserialize the entity from a response obtained outside of this crate's HTTP
+            /// client, e.g. through your own middleware. The request must have asked for JSON
+            /// (`fmt=json`).
+            #[cfg(feature = "blocking")]
+            pub fn from_response(response: reqwest::blocking::Response) -> Result<Self, crate::Error> {
+                crate::deserialization::json::from_response(response)
+            }
+
+            /// Deserialize the entity from a response obtained outside of this crate's HTTP
+            /// client, e.g. through your own middleware. The request must have asked for JSON
+            /// (`fmt=json`).
+            #[cfg(feature = "async")]
+            pub async fn from_response(response: reqwest::Response) -> Result<Self, crate::Error> {
+                crate::deserialization::json::from_response(response).await
+            }
+        })+
+    }
+}
+
+macro_rules! impl_try_build {
+    ($($t: ty), +) => {
+        $(impl $t {
+            /// Same as `build`, but fails with
+            /// [`Error::EmptySearchQuery`](crate::Error::EmptySearchQuery) when no field was set,
+            /// instead of producing a query that matches nothing useful.
+            pub fn try_build(&mut self) -> Result<String, crate::Error> {
+                if self.query.iter().all(|(value, _)| value.is_empty()) {
+                    return Err(crate::Error::EmptySearchQuery);
+                }
+                Ok(self.build())
+            }
+        })+
+    }
+}
+
+macro_rules! impl_format_normalized {
+    ($($t: ty), +) => {
+        $(impl $t {
+            /// Same as the `format` setter, but maps user-entered text to the MusicBrainz format
+            /// name first (see
+            /// [`normalize_format`](crate::entity::release::normalize_format)). Unknown formats
+            /// are searched as typed.
+            pub fn format_normalized(&mut self, format: &str) -> &mut Self {
+                match $crate::entity::release::normalize_format(format) {
+                    // Quotes must be escaped inside a Lucene phrase, e.g. `12" Vinyl`.
+                    Some(normalized) => self.format(&normalized.as_str().replace('"', "\\\"")),
+                    None => self.format(format),
+                }
+            }
+        })+
+    }
+}
+
+macro_rules! impl_disambiguation {
+    ($($t: ty), +) => {
+        $(impl $t {
+            /// (part of) the disambiguation comment. Same as the `comment` setter, MusicBrainz
+            /// indexes the disambiguation in the `comment` field.
+            pub fn disambiguation(&mut self, disambiguation: &str) -> &mut Self {
+                self.comment(disambiguation)
+            }
+        })+
+    }
+}
+
+macro_rules! impl_isni {
+    ($($t: ty), +) => {
+        $(impl $t {
+            /// Checked version of the `isni` setter. Spaces and hyphens are removed, as
+            /// MusicBrainz indexes ISNIs without them, and the code must then be 15 digits
+            /// followed by a digit or `X` check character. The check digit itself isn't
+            /// verified. Fails with [`Error::InvalidIsni`](crate::Error::InvalidIsni) otherwise.
+            pub fn try_isni(&mut self, isni: &str) -> Result<&mut Self, crate::Error> {
+                let isni = normalize_isni(isni)
+                    .ok_or_else(|| crate::Error::InvalidIsni(isni.to_string()))?;
+                Ok(self.isni(&isni))
+            }
+        })+
+    }
+}
+
+/// Loosely validate an ISNI, e.g. `0000 0001 2144 1970`, and return it without separators.
+fn normalize_isni(isni: &str) -> Option<String> {
+    let isni: String = isni
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    if !isni.is_ascii() {
+        return None;
+    }
+    let (code, check) = isni.split_at(isni.len().checked_sub(1)?);
+    let valid = code.len() == 15
+        && code.chars().all(|c| c.is_ascii_digit())
+        && (check == "X" || check.chars().all(|c| c.is_ascii_digit()));
+    valid.then_some(isni)
+}
+
+macro_rules! impl_exists {
+    ($($t: ty), +) => {
+        $(impl $t {
+            /// Check whether an entity with the given MBID exists, without downloading it.
+            #[cfg(feature = "blocking")]
+            pub fn exists(mbid: &str) -> Result<bool, crate::Error> {
+                let mbid: Mbid = mbid.parse()?;
+                crate::exists_at(&format!("{}/{}/{}", base_url(), <$t as Path>::path(), mbid))
+            }
+
+            /// Check whether an entity with the given MBID exists, without downloading it.
+            #[cfg(feature = "async")]
+            pub async fn exists(mbid: &str) -> Result<bool, crate::Error> {
+                let mbid: Mbid = mbid.parse()?;
+                crate::exists_at(&format!("{}/{}/{}", base_url(), <$t as Path>::path(), mbid)).await
+            }
+        })+
+    }
+}
+
+/// With the `tracing` feature, log a warning with the raw value whenever one of these enums
+/// falls back to its `#[serde(other)]` variant, to learn about values added to MusicBrainz.
+/// The enums derive their serde impls with `#[serde(remote = "Self")]`, which the impls below
+/// wrap.
+macro_rules! impl_unrecognized_warning {
+    ($($t: ty => $other: ident), +) => {
+        $(
+        #[cfg(feature = "tracing")]
+        impl<'de> serde::Deserialize<'de> for $t {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let raw = <String as serde::Deserialize>::deserialize(deserializer)?;
+                let value = <$t>::deserialize(
+                    serde::de::value::StrDeserializer::<D::Error>::new(&raw),
+                )?;
+                if value == <$t>::$other {
+                    tracing::warn!(value = %raw, "unrecognized {} from MusicBrainz", stringify!($t));
+                }
+                Ok(value)
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        impl serde::Serialize for $t {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                <$t>::serialize(self, serializer)
+            }
+        }
+        )+
+    }
+}
+
+impl_unrecognized_warning!(
+    alias::AliasType => UnrecognizedAliasType,
+    area::AreaType => UnrecognizedAreaType,
+    artist::ArtistType => UnrecognizedArtistType,
+    artist::Gender => UnrecognizedGender,
+    event::EventType => UnrecognizedEventType,
+    instrument::InstrumentType => UnrecognizedInstrumentType,
+    label::LabelType => UnrecognizedLabelType,
+    place::PlaceType => UnrecognizedPlaceType,
+    release::Language => UnrecognizedLanguage,
+    release::ReleasePackaging => UnrecognizedReleasePackaging,
+    release::ReleaseScript => UnrecognizedReleaseScript,
+    release::ReleaseStatus => UnrecognizedReleaseStatus,
+    release_group::ReleaseGroupPrimaryType => UnrecognizedReleaseGroupPrimaryType,
+    release_group::ReleaseGroupSecondaryType => UnrecognizedReleaseGroupSecondaryType,
+    series::SeriesType => UnrecognizedSeriesType
+);
+
 pub mod alias;
 pub mod annotation;
 pub mod area;
 pub mod artist;
 pub mod artist_credit;
 pub mod cdstub;
+pub mod country;
 pub mod coverart;
+pub mod disc;
 pub mod event;
 pub mod genre;
 pub mod instrument;
 pub mod label;
 pub mod lifespan;
+pub mod mbid;
 pub mod place;
 pub mod rating;
 pub mod recording;
@@ -119,6 +326,55 @@ impl Fetch<'_> for Url {}
 
 impl_fetchcoverart!(Release, ReleaseGroup);
 
+impl_disambiguation!(
+    area::AreaSearchQueryLuceneQueryBuilder,
+    artist::ArtistSearchQueryLuceneQueryBuilder,
+    event::EventSearchQueryLuceneQueryBuilder,
+    instrument::InstrumentSearchQueryLuceneQueryBuilder,
+    label::LabelSearchQueryLuceneQueryBuilder,
+    place::PlaceSearchQueryLuceneQueryBuilder,
+    recording::RecordingSearchQueryLuceneQueryBuilder,
+    release::ReleaseSearchQueryLuceneQueryBuilder,
+    release_group::ReleaseGroupSearchQueryLuceneQueryBuilder,
+    series::SeriesSearchQueryLuceneQueryBuilder,
+    work::WorkSearchQueryLuceneQueryBuilder
+);
+
+impl_isni!(
+    artist::ArtistSearchQueryLuceneQueryBuilder,
+    label::LabelSearchQueryLuceneQueryBuilder
+);
+
+impl_exists!(
+    Artist,
+    Recording,
+    ReleaseGroup,
+    Release,
+    Work,
+    Label,
+    Area,
+    Event,
+    Instrument,
+    Place,
+    Series,
+    Url
+);
+
+impl_from_response!(
+    Artist,
+    Recording,
+    ReleaseGroup,
+    Release,
+    Work,
+    Label,
+    Area,
+    Event,
+    Instrument,
+    Place,
+    Series,
+    Url
+);
+
 impl Browse<'_> for Artist {}
 impl Browse<'_> for Area {}
 impl Browse<'_> for Recording {}
@@ -137,10 +393,12 @@ impl Search<'_> for Artist {}
 impl Search<'_> for Event {}
 impl Search<'_> for Instrument {}
 impl Search<'_> for Label {}
+impl Search<'_> for Place {}
 impl Search<'_> for Recording {}
 impl Search<'_> for Release {}
 impl Search<'_> for ReleaseGroup {}
 impl Search<'_> for Series {}
+impl Search<'_> for Tag {}
 impl Search<'_> for Work {}
 impl Search<'_> for CDStub {}
 
@@ -216,6 +474,12 @@ impl Path<'_> for Series {
     }
 }
 
+impl Path<'_> for Tag {
+    fn path() -> &'static str {
+        "tag"
+    }
+}
+
 impl Path<'_> for Url {
     fn path() -> &'static str {
         "url"
@@ -262,12 +526,14 @@ pub(crate) enum Subquery {
     Works,
     Tags,
     Rating,
+    UserRatings,
     Genres,
     Annotations,
     Artists,
     Series,
     Instruments,
     ISRCs,
+    Media,
 }
 
 impl Subquery {
@@ -277,6 +543,7 @@ impl Subquery {
             Subquery::Recordings => "recordings",
             Subquery::Tags => "tags",
             Subquery::Rating => "ratings",
+            Subquery::UserRatings => "user-ratings",
             Subquery::Aliases => "aliases",
             Subquery::Genres => "genres",
             Subquery::Annotations => "annotation",
@@ -294,6 +561,7 @@ impl Subquery {
             Subquery::Instruments => "instruments",
             Subquery::Series => "series",
             Subquery::ISRCs => "isrcs",
+            Subquery::Media => "media",
         }
     }
 }
@@ -318,6 +586,22 @@ pub(crate) enum Relationship {
 }
 
 impl Relationship {
+    /// The relationships with other entities, which every entity lookup accepts.
+    const ENTITIES: [Relationship; 12] = [
+        Relationship::Area,
+        Relationship::Artist,
+        Relationship::Event,
+        Relationship::Instrument,
+        Relationship::Label,
+        Relationship::Place,
+        Relationship::Recording,
+        Relationship::Release,
+        Relationship::ReleaseGroup,
+        Relationship::Series,
+        Relationship::Url,
+        Relationship::Work,
+    ];
+
     pub(crate) fn as_str(&self) -> &'static str {
         match self {
             Relationship::Area => "area-rels",
@@ -338,6 +622,23 @@ impl Relationship {
     }
 }
 
+/// All the relationship includes of an entity, for `with_all_relations`: the relationships with
+/// every entity type, and the recording and work level relationships found in its `includes`.
+pub(crate) fn all_relations(includes: impl IntoIterator<Item = Include>) -> Vec<Include> {
+    let levels = includes.into_iter().filter(|include| {
+        matches!(
+            include,
+            Include::Relationship(Relationship::RecordingLevel | Relationship::WorkLevel)
+        )
+    });
+    Relationship::ENTITIES
+        .iter()
+        .cloned()
+        .map(Include::Relationship)
+        .chain(levels)
+        .collect()
+}
+
 pub(crate) enum BrowseBy {
     Area,
     Artist,
@@ -380,6 +681,57 @@ pub struct BrowseResult<T> {
     pub entities: Vec<T>,
 }
 
+impl<T> BrowseResult<T> {
+    /// The number of pages of `limit` entities needed to browse all the `count` results.
+    /// Returns `0` if `limit` is `0`.
+    pub fn total_pages(&self, limit: u8) -> i32 {
+        if limit == 0 {
+            return 0;
+        }
+        let limit = i32::from(limit);
+        (self.count.max(0) + limit - 1) / limit
+    }
+
+    /// The page this result is on, starting at `1`, when browsing `limit` entities at a time.
+    /// Returns `0` if `limit` is `0`.
+    pub fn current_page(&self, limit: u8) -> i32 {
+        if limit == 0 {
+            return 0;
+        }
+        self.offset.max(0) / i32::from(limit) + 1
+    }
+
+    /// Merge the results of browsing several entities, e.g. the releases of several labels.
+    /// The entities keep the order they first appear in, and an entity found in several
+    /// results is only kept once. `count` is the sum of the counts, minus the duplicates
+    /// dropped; `offset` is the one of `self`.
+    pub fn merge(self, others: impl IntoIterator<Item = BrowseResult<T>>) -> BrowseResult<T>
+    where
+        T: Identified,
+    {
+        let offset = self.offset;
+        let mut count = 0;
+        let mut seen = HashSet::new();
+        let mut entities = vec![];
+        for result in std::iter::once(self).chain(others) {
+            count += result.count;
+            for entity in result.entities {
+                if seen.insert(entity.mbid().clone()) {
+                    entities.push(entity);
+                } else {
+                    count -= 1;
+                }
+            }
+        }
+
+        BrowseResult {
+            count,
+            offset,
+            entities,
+        }
+    }
+}
+
 pub trait Browsable {
     const COUNT_FIELD: &'static str;
     const OFFSET_FIELD: &'static str;
@@ -495,3 +847,275 @@ impl CoverartResolution {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn browse_result(count: i32, offset: i32) -> BrowseResult<()> {
+        BrowseResult {
+            count,
+            offset,
+            entities: vec![],
+        }
+    }
+
+    #[test]
+    fn should_deserialize_from_json_value() {
+        let value = serde_json::json!({
+            "id": "5b11f4ce-a62d-471e-81fc-a69a8278c7da",
+            "name": "Nirvana",
+            "sort-name": "Nirvana",
+            "type": "Group",
+            "country": "US"
+        });
+
+        let nirvana = Artist::from_json_value(value).unwrap();
+
+        assert_eq!(nirvana.name, "Nirvana");
+        assert_eq!(nirvana.country.as_deref(), Some("US"));
+        assert!(Artist::from_json_value(serde_json::json!({"name": 42})).is_err());
+    }
+
+    #[test]
+    fn should_deserialize_annotations() {
+        let annotation = "Formed in Aberdeen, Washington in 1987.";
+        let fixture = |fields: serde_json::Value| {
+            let mut value = fields;
+            value["id"] = "5b11f4ce-a62d-471e-81fc-a69a8278c7da".into();
+            value["annotation"] = annotation.into();
+            value
+        };
+
+        let artist = Artist::from_json_value(fixture(serde_json::json!({"name": "Nirvana"})));
+        let label = Label::from_json_value(fixture(serde_json::json!({"name": "Sub Pop"})));
+        let work = Work::from_json_value(fixture(serde_json::json!({"title": "Lithium"})));
+        let recording =
+            Recording::from_json_value(fixture(serde_json::json!({"title": "Lithium"})));
+        let release = Release::from_json_value(fixture(serde_json::json!({"title": "Bleach"})));
+        let release_group =
+            ReleaseGroup::from_json_value(fixture(serde_json::json!({"title": "Bleach"})));
+
+        assert_eq!(artist.unwrap().annotation.as_deref(), Some(annotation));
+        assert_eq!(label.unwrap().annotation.as_deref(), Some(annotation));
+        assert_eq!(work.unwrap().annotation.as_deref(), Some(annotation));
+        assert_eq!(recording.unwrap().annotation.as_deref(), Some(annotation));
+        assert_eq!(release.unwrap().annotation.as_deref(), Some(annotation));
+        assert_eq!(
+            release_group.unwrap().annotation.as_deref(),
+            Some(annotation)
+        );
+
+        let mut query = Artist::fetch();
+        query
+            .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+            .with_annotations();
+        assert!(query.url().ends_with("?fmt=json&inc=annotation"));
+    }
+
+    #[test]
+    fn should_deserialize_tags_genres_and_ratings() {
+        let fixture = |name: &str| {
+            serde_json::json!({
+                "id": "5b11f4ce-a62d-471e-81fc-a69a8278c7da",
+                "name": name,
+                "tags": [{"name": "seattle", "count": 3}],
+                "genres": [{"name": "grunge", "count": 2}],
+                "rating": {"value": 4.5, "votes-count": 2},
+            })
+        };
+
+        let label = Label::from_json_value(fixture("Sub Pop")).unwrap();
+        let mut event = fixture("Reading Festival 1992");
+        event["time"] = "".into();
+        let event = Event::from_json_value(event).unwrap();
+        let place = Place::from_json_value(fixture("Reading Festival Site")).unwrap();
+        let area = Area::from_json_value(fixture("Seattle")).unwrap();
+        let mut instrument = fixture("electric guitar");
+        instrument["type"] = "String instrument".into();
+        instrument["type-id"] = "cc00f97f-cf3d-3ae2-9110-0df3be3b6e5b".into();
+        let instrument = Instrument::from_json_value(instrument).unwrap();
+
+        for (tags, genres) in [
+            (&label.tags, &label.genres),
+            (&event.tags, &event.genres),
+            (&place.tags, &place.genres),
+            (&area.tags, &area.genres),
+            (&instrument.tags, &instrument.genres),
+        ] {
+            assert_eq!(tags.as_ref().unwrap()[0].name, "seattle");
+            assert_eq!(genres.as_ref().unwrap()[0].name, "grunge");
+        }
+        // MusicBrainz only rates labels and events among these entities.
+        let label_rating = label.rating.unwrap();
+        assert_eq!(label_rating.value, Some(4.5));
+        assert_eq!(label_rating.vote_count, Some(2));
+        assert_eq!(event.rating.unwrap().value, Some(4.5));
+    }
+
+    #[test]
+    fn should_search_by_disambiguation() {
+        let artist = artist::ArtistSearchQuery::query_builder()
+            .artist("Nirvana")
+            .and()
+            .disambiguation("90s US grunge band")
+            .build();
+        let release_group = release_group::ReleaseGroupSearchQuery::query_builder()
+            .disambiguation("deluxe")
+            .build();
+
+        assert_eq!(
+            artist,
+            r#"query=artist:Nirvana AND comment:"90s US grunge band""#
+        );
+        assert_eq!(release_group, "query=comment:deluxe");
+    }
+
+    #[test]
+    fn should_search_by_isni_and_ipi() {
+        let artist = artist::ArtistSearchQuery::query_builder()
+            .try_isni("0000 0001 2144 1970")
+            .unwrap()
+            .and()
+            .ipi("00052210040")
+            .build();
+        let label = label::LabelSearchQuery::query_builder()
+            .try_isni("0000-0004-0506-573x")
+            .unwrap()
+            .or()
+            .ipi("00155555555")
+            .build();
+
+        assert_eq!(artist, "query=isni:0000000121441970 AND ipi:00052210040");
+        assert_eq!(label, "query=isni:000000040506573X OR ipi:00155555555");
+    }
+
+    #[test]
+    fn should_reject_malformed_isni() {
+        for isni in [
+            "",
+            "0000 0001 2144 197",
+            "0000 0001 2144 19701",
+            "X000 0001 2144 1970",
+            "é",
+            "0000 0001 2144 197é",
+        ] {
+            let mut query = artist::ArtistSearchQuery::query_builder();
+            let err = query.try_isni(isni).map(|_| ()).unwrap_err();
+
+            assert!(matches!(err, crate::Error::InvalidIsni(value) if value == isni));
+        }
+    }
+
+    #[test]
+    fn should_count_pages_with_exact_last_page() {
+        let result = browse_result(100, 50);
+
+        assert_eq!(result.total_pages(25), 4);
+        assert_eq!(result.current_page(25), 3);
+    }
+
+    #[test]
+    fn should_count_pages_with_partial_last_page() {
+        let result = browse_result(101, 100);
+
+        assert_eq!(result.total_pages(25), 5);
+        assert_eq!(result.current_page(25), 5);
+        assert_eq!(browse_result(0, 0).total_pages(25), 0);
+    }
+
+    #[test]
+    fn should_not_divide_by_zero_limit() {
+        let result = browse_result(101, 100);
+
+        assert_eq!(result.total_pages(0), 0);
+        assert_eq!(result.current_page(0), 0);
+    }
+
+    fn labels(count: i32, ids: &[&str]) -> BrowseResult<Label> {
+        let labels = ids
+            .iter()
+            .map(|id| serde_json::json!({"id": id, "name": "Sub Pop"}))
+            .collect();
+
+        BrowseResult {
+            count,
+            offset: 0,
+            entities: serde_json::from_value(serde_json::Value::Array(labels)).unwrap(),
+        }
+    }
+
+    #[test]
+    fn should_merge_browse_results() {
+        let seattle = labels(3, &["l1", "l2", "l3"]);
+        let olympia = labels(2, &["l3", "l4"]);
+
+        let merged = seattle.merge(vec![olympia]);
+
+        let ids: Vec<_> = merged
+            .entities
+            .iter()
+            .map(|label| label.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["l1", "l2", "l3", "l4"]);
+        assert_eq!(merged.count, 4);
+        assert_eq!(merged.offset, 0);
+    }
+
+    /// Counts the warnings logged while it is the default subscriber.
+    #[cfg(feature = "tracing")]
+    struct WarningCounter(std::sync::Arc<std::sync::atomic::AtomicUsize>);
+
+    #[cfg(feature = "tracing")]
+    impl tracing::Subscriber for WarningCounter {
+        fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            tracing::span::Id::from_u64(1)
+        }
+
+        fn record(&self, _: &tracing::span::Id, _: &tracing::span::Record<'_>) {}
+
+        fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            if *event.metadata().level() == tracing::Level::WARN {
+                self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            }
+        }
+
+        fn enter(&self, _: &tracing::span::Id) {}
+
+        fn exit(&self, _: &tracing::span::Id) {}
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn should_warn_on_unrecognized_value() {
+        use crate::entity::release::ReleaseStatus;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let warnings = Arc::new(AtomicUsize::new(0));
+        let counter = WarningCounter(Arc::clone(&warnings));
+        let statuses: Vec<ReleaseStatus> = tracing::subscriber::with_default(counter, || {
+            serde_json::from_str(r#"["Official", "Pseudo-Release", "Cancelled"]"#).unwrap()
+        });
+
+        assert_eq!(
+            statuses,
+            vec![
+                ReleaseStatus::Official,
+                ReleaseStatus::PseudoRelease,
+                ReleaseStatus::UnrecognizedReleaseStatus
+            ]
+        );
+        assert_eq!(warnings.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            serde_json::to_string(&ReleaseStatus::PseudoRelease).unwrap(),
+            r#""Pseudo-Release""#
+        );
+    }
+}
@@ -3,16 +3,22 @@ use crate::entity::alias::Alias;
 use crate::entity::area::Area;
 use crate::entity::genre::Genre;
 use crate::entity::lifespan::LifeSpan;
+use crate::entity::mbid::Mbid;
 use crate::entity::relations::Relation;
 use crate::entity::tag::Tag;
 use crate::entity::BrowseBy;
+#[cfg(any(feature = "blocking", feature = "async"))]
+use crate::Error;
+use lucene_query_builder::QueryBuilder;
 use serde::{Deserialize, Serialize};
+#[cfg(any(feature = "blocking", feature = "async"))]
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 #[serde(rename_all(deserialize = "kebab-case"))]
 pub struct Place {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
     /// The place name is the official name of a place.
     pub name: String,
     /// The type categorises the place based on its primary function. The possible values are:
@@ -43,18 +49,249 @@ pub struct Place {
     pub annotation: Option<String>,
 }
 
+impl Place {
+    /// Resolve the ISO 3166-1 code of the country the place is located in, by walking up the
+    /// hierarchy of its [`area`](Place::area): a city is part of a subdivision, which is part of
+    /// a country.
+    ///
+    /// Every area without a known parent is fetched with its area relations, so this sends one
+    /// request per level of the hierarchy. Returns `None` when the place has no area or when no
+    /// country is found.
+    #[cfg(feature = "blocking")]
+    pub fn country(&self) -> Result<Option<String>, Error> {
+        resolve_country(&crate::config::base_url(), self.area.as_ref())
+    }
+
+    /// Resolve the ISO 3166-1 code of the country the place is located in, by walking up the
+    /// hierarchy of its [`area`](Place::area): a city is part of a subdivision, which is part of
+    /// a country.
+    ///
+    /// Every area without a known parent is fetched with its area relations, so this sends one
+    /// request per level of the hierarchy. Returns `None` when the place has no area or when no
+    /// country is found.
+    #[cfg(feature = "async")]
+    pub async fn country(&self) -> Result<Option<String>, Error> {
+        resolve_country(&crate::config::base_url(), self.area.as_ref()).await
+    }
+}
+
+#[cfg(feature = "blocking")]
+fn resolve_country(base_url: &str, area: Option<&Area>) -> Result<Option<String>, Error> {
+    let mut hierarchy = match area {
+        Some(area) => AreaHierarchy::new(area.clone()),
+        None => return Ok(None),
+    };
+
+    loop {
+        match hierarchy.climb() {
+            Climb::Country(code) => return Ok(code),
+            Climb::Fetch(id) => {
+                let area: Area = crate::fetch_query_at(base_url)
+                    .id(&id)
+                    .with_area_relations()
+                    .execute()?;
+                hierarchy.cache(id, area);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+async fn resolve_country(base_url: &str, area: Option<&Area>) -> Result<Option<String>, Error> {
+    let mut hierarchy = match area {
+        Some(area) => AreaHierarchy::new(area.clone()),
+        None => return Ok(None),
+    };
+
+    loop {
+        match hierarchy.climb() {
+            Climb::Country(code) => return Ok(code),
+            Climb::Fetch(id) => {
+                let area: Area = crate::fetch_query_at(base_url)
+                    .id(&id)
+                    .with_area_relations()
+                    .execute()
+                    .await?;
+                hierarchy.cache(id, area);
+            }
+        }
+    }
+}
+
+/// Where [`AreaHierarchy::climb`] stopped.
+#[cfg(any(feature = "blocking", feature = "async"))]
+#[derive(Debug, PartialEq)]
+enum Climb {
+    /// The country code, or `None` if the top of the hierarchy has no country.
+    Country(Option<String>),
+    /// The area to fetch with its area relations before climbing further.
+    Fetch(Mbid),
+}
+
+/// The walk up the area hierarchy of a place, caching the areas fetched with their relations.
+#[cfg(any(feature = "blocking", feature = "async"))]
+struct AreaHierarchy {
+    current: Area,
+    fetched: HashMap<Mbid, Area>,
+    climbed: HashSet<Mbid>,
+}
+
+#[cfg(any(feature = "blocking", feature = "async"))]
+impl AreaHierarchy {
+    fn new(area: Area) -> Self {
+        AreaHierarchy {
+            current: area,
+            fetched: HashMap::new(),
+            climbed: HashSet::new(),
+        }
+    }
+
+    /// Climb as high as the known areas allow: through the parents of the current area, and
+    /// through the cached fetch of an area without a known parent.
+    fn climb(&mut self) -> Climb {
+        loop {
+            if let Some(code) = self.current.country_code() {
+                return Climb::Country(Some(code.to_string()));
+            }
+            if let Some(parent) = self.current.parent_area() {
+                self.current = parent.clone();
+                continue;
+            }
+
+            let id = self.current.id.clone();
+            match self.fetched.get(&id) {
+                Some(area) if self.climbed.insert(id.clone()) => self.current = area.clone(),
+                // Already climbed through its fetch and still no parent: top of the hierarchy.
+                Some(_) => return Climb::Country(None),
+                None => return Climb::Fetch(id),
+            }
+        }
+    }
+
+    fn cache(&mut self, id: Mbid, area: Area) {
+        self.fetched.insert(id, area);
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct Coordinates {
     pub latitude: f64,
     pub longitude: f64,
 }
 
+/// A rectangular geographic area, in decimal degrees.
+///
+/// The MusicBrainz search server has no radius query, so proximity search is done with a box:
+/// either server side with
+/// [`PlaceSearchQueryLuceneQueryBuilder::bounding_box`], which turns it into `lat` and `long`
+/// range queries, or client side with [`BoundingBox::contains`] on already fetched places (e.g.
+/// the places of an area, browsed with `Place::browse().by_area(..)`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min_latitude: f64,
+    pub max_latitude: f64,
+    pub min_longitude: f64,
+    pub max_longitude: f64,
+}
+
+impl BoundingBox {
+    /// The box spanning `radius_km` kilometers around a point, in every direction. The box is
+    /// approximate, and not meant for use near the poles or across the antimeridian.
+    pub fn around(latitude: f64, longitude: f64, radius_km: f64) -> Self {
+        const KM_PER_DEGREE: f64 = 111.32;
+        let latitude_delta = radius_km / KM_PER_DEGREE;
+        let longitude_delta = radius_km / (KM_PER_DEGREE * latitude.to_radians().cos());
+
+        BoundingBox {
+            min_latitude: latitude - latitude_delta,
+            max_latitude: latitude + latitude_delta,
+            min_longitude: longitude - longitude_delta,
+            max_longitude: longitude + longitude_delta,
+        }
+    }
+
+    /// Returns `true` if the coordinates are inside the box, borders included.
+    pub fn contains(&self, coordinates: &Coordinates) -> bool {
+        (self.min_latitude..=self.max_latitude).contains(&coordinates.latitude)
+            && (self.min_longitude..=self.max_longitude).contains(&coordinates.longitude)
+    }
+
+    /// Keep the places located inside the box. Places without coordinates are left out.
+    pub fn filter<'a>(&self, places: &'a [Place]) -> Vec<&'a Place> {
+        places
+            .iter()
+            .filter(|place| {
+                place
+                    .coordinates
+                    .as_ref()
+                    .map_or(false, |c| self.contains(c))
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, QueryBuilder)]
+pub struct PlaceSearchQuery {
+    /// (part of) the physical address for this place
+    pub address: String,
+    /// (part of) any alias attached to the place (diacritics are ignored)
+    pub alias: String,
+    /// (part of) the name of the place's main associated area
+    pub area: String,
+    /// the place's begin date (e.g. "1980-01-22")
+    pub begin: String,
+    /// (part of) the place's disambiguation comment
+    pub comment: String,
+    /// the place's end date (e.g. "1980-01-22")
+    pub end: String,
+    /// a boolean flag (true/false) indicating whether or not the place has ended (is closed)
+    pub ended: String,
+    /// the place's latitude
+    pub lat: String,
+    /// the place's longitude
+    pub long: String,
+    /// (part of) the place's name (diacritics are ignored)
+    pub place: String,
+    /// (part of) the place's name (with the specified diacritics)
+    #[query_builder_field = "placeaccent"]
+    pub place_accent: String,
+    /// the place's MBID
+    pub pid: String,
+    /// the place's type
+    #[query_builder_field = "type"]
+    pub place_type: String,
+}
+
+impl_try_build!(PlaceSearchQueryLuceneQueryBuilder);
+
+impl PlaceSearchQueryLuceneQueryBuilder {
+    /// Typed version of the `place_type` setter.
+    pub fn place_type_typed(&mut self, place_type: PlaceType) -> &mut Self {
+        self.place_type(place_type.as_str())
+    }
+
+    /// Search places located inside `bounding_box`, with `lat` and `long` range queries. Like the
+    /// other setters, chain it to the previous one with `and()`.
+    pub fn bounding_box(&mut self, bounding_box: &BoundingBox) -> &mut Self {
+        self.lat_range(
+            &bounding_box.min_latitude.to_string(),
+            &bounding_box.max_latitude.to_string(),
+        )
+        .and()
+        .long_range(
+            &bounding_box.min_longitude.to_string(),
+            &bounding_box.max_longitude.to_string(),
+        )
+    }
+}
+
 /// The type of a MusicBrainz place entity.
 /// Note that this enum is `non_exhaustive`; The list of place types is subject to change and these
 /// changes are only reflected in the DB, not in actual MB code.
 /// Variants are derived from the `place_type` table in the MusicBrainz database.
 #[non_exhaustive]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "tracing", serde(remote = "Self"))]
 pub enum PlaceType {
     /// A place designed for non-live production of music, typically a recording studio.
     Studio,
@@ -88,6 +325,24 @@ pub enum PlaceType {
     UnrecognizedPlaceType,
 }
 
+impl PlaceType {
+    /// The name MusicBrainz gives to the type, as used by the search API `type` field.
+    /// `UnrecognizedPlaceType` maps to an empty string since the original value is not kept.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlaceType::Studio => "Studio",
+            PlaceType::Venue => "Venue",
+            PlaceType::Stadium => "Stadium",
+            PlaceType::IndoorArena => "Indoor arena",
+            PlaceType::EducationalInstitution => "Educational institution",
+            PlaceType::ReligiousBuilding => "Religious building",
+            PlaceType::PressingPlant => "Pressing plant",
+            PlaceType::Other => "Other",
+            PlaceType::UnrecognizedPlaceType => "",
+        }
+    }
+}
+
 impl_browse! {
 Place,
    (by_area, BrowseBy::Area),
@@ -113,3 +368,254 @@ impl_includes!(
     (with_genres, Include::Subquery(Subquery::Genres)),
     (with_annotations, Include::Subquery(Subquery::Annotations))
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLACE: &str = r#"{
+        "id": "4352063b-a833-421b-a420-e7fb295dece0",
+        "name": "Royal Albert Hall",
+        "type": "Venue",
+        "area": {
+            "id": "f03d09b3-39dc-4083-afd6-159e3f0d462f",
+            "name": "London",
+            "sort-name": "London",
+            "disambiguation": ""
+        }
+    }"#;
+
+    const LONDON: &str = r#"{
+        "id": "f03d09b3-39dc-4083-afd6-159e3f0d462f",
+        "name": "London",
+        "sort-name": "London",
+        "type": "City",
+        "disambiguation": "",
+        "relations": [
+            {
+                "type": "part of",
+                "type-id": "de7cc874-8b1b-3a05-8272-f3834c968fb7",
+                "direction": "backward",
+                "target-type": "area",
+                "area": {
+                    "id": "9d5dd675-3cf4-4296-9e39-67865ebee758",
+                    "name": "England",
+                    "sort-name": "England",
+                    "disambiguation": ""
+                }
+            },
+            {
+                "type": "part of",
+                "type-id": "de7cc874-8b1b-3a05-8272-f3834c968fb7",
+                "direction": "forward",
+                "target-type": "area",
+                "area": {
+                    "id": "c249c30e-88ab-4b2f-9745-96a25f3f9c2b",
+                    "name": "Camden",
+                    "sort-name": "Camden",
+                    "disambiguation": ""
+                }
+            }
+        ]
+    }"#;
+
+    const ENGLAND: &str = r#"{
+        "id": "9d5dd675-3cf4-4296-9e39-67865ebee758",
+        "name": "England",
+        "sort-name": "England",
+        "type": "Subdivision",
+        "disambiguation": "",
+        "relations": [
+            {
+                "type": "part of",
+                "type-id": "de7cc874-8b1b-3a05-8272-f3834c968fb7",
+                "direction": "backward",
+                "target-type": "area",
+                "area": {
+                    "id": "8a754a16-0027-3a29-b6d7-2b40ea0481ed",
+                    "name": "United Kingdom",
+                    "sort-name": "United Kingdom",
+                    "disambiguation": "",
+                    "iso-3166-1-codes": ["GB"]
+                }
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn should_deserialize_recording_studio() {
+        let abbey_road: Place = serde_json::from_str(
+            r#"{
+                "id": "bd55aeb7-19d1-4607-a500-14b8479d3fed",
+                "name": "Abbey Road Studios",
+                "type": "Studio",
+                "type-id": "05fa6a09-ac2b-3cfc-a64e-b7ec9ac3ed0c",
+                "address": "3 Abbey Road, St John’s Wood, London, NW8 9AY",
+                "coordinates": {"latitude": 51.531986, "longitude": -0.178214},
+                "life-span": {"begin": "1931-11", "end": null, "ended": false},
+                "disambiguation": ""
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(abbey_road.place_type, Some(PlaceType::Studio));
+        assert_eq!(
+            abbey_road.type_id.as_deref(),
+            Some("05fa6a09-ac2b-3cfc-a64e-b7ec9ac3ed0c")
+        );
+
+        let place_type: PlaceType = serde_json::from_str(r#""Amphitheatre""#).unwrap();
+        assert_eq!(place_type, PlaceType::UnrecognizedPlaceType);
+    }
+
+    #[test]
+    fn should_build_typed_place_type_query() {
+        let studio = PlaceSearchQuery::query_builder()
+            .place_type_typed(PlaceType::Studio)
+            .build();
+        let church = PlaceSearchQuery::query_builder()
+            .place_type_typed(PlaceType::ReligiousBuilding)
+            .build();
+
+        assert_eq!(studio, "query=type:Studio");
+        assert_eq!(church, r#"query=type:"Religious building""#);
+    }
+
+    #[test]
+    fn should_read_parent_area_from_relations() {
+        let london: Area = serde_json::from_str(LONDON).unwrap();
+        let england: Area = serde_json::from_str(ENGLAND).unwrap();
+
+        assert_eq!(london.country_code(), None);
+        assert_eq!(london.parent_area().unwrap().name, "England");
+        assert_eq!(
+            england.parent_area().and_then(Area::country_code),
+            Some("GB")
+        );
+    }
+
+    #[cfg(any(feature = "blocking", feature = "async"))]
+    #[test]
+    fn should_stop_at_top_of_area_hierarchy() {
+        let england: Area = serde_json::from_str(ENGLAND).unwrap();
+        let mut top = england.clone();
+        top.relations = None;
+        let mut hierarchy = AreaHierarchy::new(top);
+
+        assert_eq!(hierarchy.climb(), Climb::Fetch(england.id.clone()));
+        hierarchy.cache(england.id.clone(), england.clone());
+        assert_eq!(hierarchy.climb(), Climb::Country(Some("GB".to_string())));
+
+        let mut cyclic = england.clone();
+        cyclic.relations = None;
+        let mut hierarchy = AreaHierarchy::new(cyclic.clone());
+        hierarchy.cache(cyclic.id.clone(), cyclic);
+        assert_eq!(hierarchy.climb(), Climb::Country(None));
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn should_resolve_place_country() {
+        let mut server = mockito::Server::new();
+        let london = server
+            .mock("GET", "/area/f03d09b3-39dc-4083-afd6-159e3f0d462f")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "inc".to_string(),
+                "area-rels".to_string(),
+            ))
+            .with_body(LONDON)
+            .expect(1)
+            .create();
+        let england = server
+            .mock("GET", "/area/9d5dd675-3cf4-4296-9e39-67865ebee758")
+            .match_query(mockito::Matcher::Any)
+            .with_body(ENGLAND)
+            .expect(1)
+            .create();
+
+        let place: Place = serde_json::from_str(PLACE).unwrap();
+        let country = resolve_country(&server.url(), place.area.as_ref()).unwrap();
+
+        london.assert();
+        england.assert();
+        assert_eq!(country.as_deref(), Some("GB"));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_resolve_place_country() {
+        let mut server = mockito::Server::new_async().await;
+        let london = server
+            .mock("GET", "/area/f03d09b3-39dc-4083-afd6-159e3f0d462f")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "inc".to_string(),
+                "area-rels".to_string(),
+            ))
+            .with_body(LONDON)
+            .expect(1)
+            .create_async()
+            .await;
+        let england = server
+            .mock("GET", "/area/9d5dd675-3cf4-4296-9e39-67865ebee758")
+            .match_query(mockito::Matcher::Any)
+            .with_body(ENGLAND)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let place: Place = serde_json::from_str(PLACE).unwrap();
+        let country = resolve_country(&server.url(), place.area.as_ref())
+            .await
+            .unwrap();
+
+        london.assert_async().await;
+        england.assert_async().await;
+        assert_eq!(country.as_deref(), Some("GB"));
+    }
+
+    #[test]
+    fn should_filter_places_by_bounding_box() {
+        let places: Vec<Place> = serde_json::from_str(
+            r#"[
+                {"id": "4352063b-a833-421b-a420-e7fb295dece0", "name": "Royal Albert Hall",
+                 "coordinates": {"latitude": 51.50097, "longitude": -0.17722}},
+                {"id": "bea135c0-a32e-49be-85fd-9234c73fa0a8", "name": "Abbey Road Studios",
+                 "coordinates": {"latitude": 51.53192, "longitude": -0.17834}},
+                {"id": "380eaa9c-1ce6-4e5b-8d57-cde5ba7d2fb3", "name": "Paradiso",
+                 "coordinates": {"latitude": 52.36222, "longitude": 4.88361}},
+                {"id": "5e1b4de4-c7fb-4bb4-9cc2-7b4a5f1e5f0c", "name": "Unknown venue"}
+            ]"#,
+        )
+        .unwrap();
+
+        let london = BoundingBox::around(51.5074, -0.1278, 10.0);
+        let names: Vec<&str> = london
+            .filter(&places)
+            .iter()
+            .map(|place| place.name.as_str())
+            .collect();
+
+        assert_eq!(names, vec!["Royal Albert Hall", "Abbey Road Studios"]);
+    }
+
+    #[test]
+    fn should_search_places_in_bounding_box() {
+        let bounding_box = BoundingBox {
+            min_latitude: 51.4,
+            max_latitude: 51.6,
+            min_longitude: -0.3,
+            max_longitude: 0.1,
+        };
+
+        let query = PlaceSearchQuery::query_builder()
+            .place("studios")
+            .and()
+            .bounding_box(&bounding_box)
+            .build();
+
+        assert_eq!(
+            query,
+            "query=place:studios AND lat:[51.4 TO 51.6] AND long:[-0.3 TO 0.1]"
+        );
+    }
+}
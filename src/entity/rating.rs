@@ -3,6 +3,118 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 #[serde(rename_all(deserialize = "kebab-case"))]
 pub struct Rating {
+    /// The number of users who rated the entity.
+    #[serde(alias = "votes-count")]
     pub vote_count: Option<u32>,
     pub value: Option<f32>,
 }
+
+impl Rating {
+    /// The average rating, out of 5, or `None` if nobody rated the entity.
+    pub fn average(&self) -> Option<f32> {
+        match self.vote_count {
+            Some(0) => None,
+            _ => self.value,
+        }
+    }
+}
+
+/// Implemented by the entities users can rate: artists, events, labels, recordings, release
+/// groups and works.
+pub trait Rated {
+    /// The rating loaded with the `ratings` include.
+    fn rating(&self) -> Option<&Rating>;
+
+    /// The rating the authenticated user gave, loaded with the `user-ratings` include. `None`
+    /// unless implemented.
+    fn user_rating(&self) -> Option<&Rating> {
+        None
+    }
+
+    /// The average rating, out of 5. Returns `None` when ratings were not requested or when
+    /// nobody rated the entity.
+    fn average_rating(&self) -> Option<f32> {
+        self.rating().and_then(Rating::average)
+    }
+}
+
+macro_rules! impl_rated {
+    ($($t: ty), +) => {
+        $(impl Rated for $t {
+            fn rating(&self) -> Option<&Rating> {
+                self.rating.as_ref()
+            }
+
+            fn user_rating(&self) -> Option<&Rating> {
+                self.user_rating.as_ref()
+            }
+        })+
+    }
+}
+
+impl_rated!(
+    crate::entity::artist::Artist,
+    crate::entity::event::Event,
+    crate::entity::label::Label,
+    crate::entity::recording::Recording,
+    crate::entity::release_group::ReleaseGroup,
+    crate::entity::work::Work
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::label::Label;
+    use crate::entity::recording::Recording;
+    use crate::entity::work::Work;
+    use crate::Fetch;
+
+    #[test]
+    fn should_read_average_rating() {
+        let rated: Label = serde_json::from_value(serde_json::json!({
+            "id": "f1b5c4ae-4ba4-4c29-bc88-a2e1a5a3e9c5",
+            "name": "Sub Pop",
+            "rating": {"value": 4.2, "votes-count": 5}
+        }))
+        .unwrap();
+        let unrated: Work = serde_json::from_value(serde_json::json!({
+            "id": "8a0b2c3e-9735-3fd1-83f4-1e3d4cd4f1f5",
+            "title": "Lithium",
+            "rating": {"value": null, "votes-count": 0}
+        }))
+        .unwrap();
+        let not_requested: Work = serde_json::from_value(serde_json::json!({
+            "id": "8a0b2c3e-9735-3fd1-83f4-1e3d4cd4f1f5",
+            "title": "Lithium"
+        }))
+        .unwrap();
+
+        assert_eq!(rated.average_rating(), Some(4.2));
+        assert_eq!(unrated.average_rating(), None);
+        assert_eq!(not_requested.average_rating(), None);
+    }
+
+    #[test]
+    fn should_read_rating_and_user_rating() {
+        let recording: Recording = serde_json::from_value(serde_json::json!({
+            "id": "5fb524f1-8cc8-4c04-a921-e34c0a911ea7",
+            "title": "Smells Like Teen Spirit",
+            "rating": {"value": 3.8, "votes-count": 12},
+            "user-rating": {"value": 5}
+        }))
+        .unwrap();
+
+        assert_eq!(recording.average_rating(), Some(3.8));
+        assert_eq!(
+            recording.user_rating().and_then(|rating| rating.value),
+            Some(5.0)
+        );
+
+        let mut query = Recording::fetch();
+        query
+            .id("5fb524f1-8cc8-4c04-a921-e34c0a911ea7")
+            .with_ratings()
+            .with_user_ratings();
+        assert!(query.url().ends_with("?fmt=json&inc=ratings+user-ratings"));
+    }
+}
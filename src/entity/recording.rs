@@ -2,14 +2,19 @@ use crate::date_format;
 use crate::entity::alias::Alias;
 use crate::entity::artist_credit::ArtistCredit;
 use crate::entity::genre::Genre;
+use crate::entity::mbid::Mbid;
 use crate::entity::rating::Rating;
 use crate::entity::relations::Relation;
 use crate::entity::release::Release;
 use crate::entity::tag::Tag;
 use crate::entity::BrowseBy;
+use crate::entity::BrowseResult;
 use crate::entity::{Include, Relationship, Subquery};
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "submission")]
+use crate::submission::{self, SubmissionError, SubmissionResponse};
+
 use chrono::NaiveDate;
 use lucene_query_builder::QueryBuilder;
 
@@ -25,7 +30,7 @@ use lucene_query_builder::QueryBuilder;
 #[serde(rename_all(deserialize = "kebab-case"))]
 pub struct Recording {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
     /// The title of the recording.
     pub title: String,
 
@@ -43,13 +48,20 @@ pub struct Recording {
     pub isrcs: Option<Vec<String>>,
     pub relations: Option<Vec<Relation>>,
     pub releases: Option<Vec<Release>>,
+    /// The release date of the earliest release including this recording.
+    #[serde(default)]
+    #[serde(deserialize_with = "date_format::deserialize_opt")]
+    pub first_release_date: Option<NaiveDate>,
     /// Artist credits indicate who is the main credited artist (or artists) for releases, release
     /// groups, tracks and recordings, and how they are credited.
+    /// Only present when fetched with `with_artists` or `with_artist_credits` (`inc=artist-credits`).
     pub artist_credit: Option<Vec<ArtistCredit>>,
     /// Aliases are alternate names for a recording.
     pub aliases: Option<Vec<Alias>>,
     pub tags: Option<Vec<Tag>>,
     pub rating: Option<Rating>,
+    /// The rating the authenticated user gave, loaded with the `user-ratings` include.
+    pub user_rating: Option<Rating>,
     /// Genres are currently supported in MusicBrainz as part of the tag system.
     pub genres: Option<Vec<Genre>>,
     /// Annotations are text fields, functioning like a miniature wiki, that can be added to any
@@ -57,6 +69,106 @@ pub struct Recording {
     pub annotation: Option<String>,
 }
 
+impl Recording {
+    /// Returns `true` if this recording is a video. Recordings without the `video` flag are
+    /// considered audio recordings.
+    pub fn is_video(&self) -> bool {
+        self.video == Some(true)
+    }
+
+    /// The release best representing the recording among its loaded releases, see
+    /// [`Release::canonical`]. Requires the recording to be fetched with
+    /// [`with_releases`](crate::FetchQuery::with_releases), and with
+    /// [`with_release_groups`](crate::FetchQuery::with_release_groups) for the release type to be
+    /// taken into account.
+    pub fn canonical_release(&self) -> Option<&Release> {
+        Release::canonical(self.releases.iter().flatten())
+    }
+
+    /// Returns `true` if `release` is where this recording first came out, `false` if it is a
+    /// later appearance like a reissue or a compilation.
+    ///
+    /// Dates missing a month or a day count as the start of the year or month, so a release
+    /// only dated `1991` is original for a recording first released on `1991-09-24`. Returns
+    /// `false` when either date is unknown.
+    pub fn is_original_on(&self, release: &Release) -> bool {
+        match (release.date, self.first_release_date) {
+            (Some(release_date), Some(first_release_date)) => release_date <= first_release_date,
+            _ => false,
+        }
+    }
+
+    /// The recording genres, or when it has none, the genres of its releases. Genre-tagging a
+    /// song often has to rely on album genres.
+    ///
+    /// Each release contributes its own genres, or those of its release group if it has none.
+    /// Votes for the same genre are summed, and the genres are sorted by decreasing count.
+    /// The releases must be loaded with their genres, e.g. by fetching them with
+    /// [`with_genres`](crate::FetchQuery::with_genres), as MusicBrainz only returns the recording
+    /// genres with `inc=releases+genres`.
+    pub fn genres_or_release_genres(&self) -> Vec<Genre> {
+        if let Some(genres) = self.genres.as_ref().filter(|genres| !genres.is_empty()) {
+            return genres.clone();
+        }
+
+        let mut aggregated: Vec<Genre> = vec![];
+        for release in self.releases.iter().flatten() {
+            let genres = release
+                .genres
+                .as_ref()
+                .filter(|genres| !genres.is_empty())
+                .or_else(|| release.release_group.as_ref()?.genres.as_ref());
+            for genre in genres.into_iter().flatten() {
+                match aggregated.iter_mut().find(|known| known.name == genre.name) {
+                    Some(known) => known.count += genre.count,
+                    None => aggregated.push(genre.clone()),
+                }
+            }
+        }
+        aggregated.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+        aggregated
+    }
+}
+
+#[cfg(all(feature = "submission", feature = "blocking"))]
+impl Recording {
+    /// Submit `isrcs` for the recording with the given MBID. Every ISRC is validated before
+    /// anything is sent. See [`crate::submission`].
+    pub fn submit_isrcs(mbid: &str, isrcs: &[&str]) -> Result<SubmissionResponse, SubmissionError> {
+        let body = submission::isrc_submission_body(mbid, isrcs)?;
+        submission::submit(&crate::config::base_url(), "recording", body)
+    }
+}
+
+#[cfg(all(feature = "submission", feature = "async"))]
+impl Recording {
+    /// Submit `isrcs` for the recording with the given MBID. Every ISRC is validated before
+    /// anything is sent. See [`crate::submission`].
+    pub async fn submit_isrcs(
+        mbid: &str,
+        isrcs: &[&str],
+    ) -> Result<SubmissionResponse, SubmissionError> {
+        let body = submission::isrc_submission_body(mbid, isrcs)?;
+        submission::submit(&crate::config::base_url(), "recording", body).await
+    }
+}
+
+impl BrowseResult<Recording> {
+    /// Remove the video recordings from the browsed entities.
+    /// Note that `count` still refers to the total number of recordings, videos included.
+    pub fn exclude_videos(mut self) -> Self {
+        self.entities.retain(|recording| !recording.is_video());
+        self
+    }
+
+    /// Keep only the video recordings in the browsed entities.
+    /// Note that `count` still refers to the total number of recordings, audio included.
+    pub fn only_videos(mut self) -> Self {
+        self.entities.retain(Recording::is_video);
+        self
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize, QueryBuilder)]
 pub struct RecordingSearchQuery {
     /// (part of) any alias attached to the recording (diacritics are ignored)
@@ -140,22 +252,40 @@ pub struct RecordingSearchQuery {
     pub video: bool,
 }
 
+impl_try_build!(RecordingSearchQueryLuceneQueryBuilder);
+
+impl_format_normalized!(RecordingSearchQueryLuceneQueryBuilder);
+
 impl_browse! {
 Recording,
    (by_release, BrowseBy::Release),
    (by_artist, BrowseBy::Artist),
-   (by_work, BrowseBy::Work)
+   (by_work, BrowseBy::Work),
+   (by_collection, BrowseBy::Collection)
 }
 
 impl_includes!(
     Recording,
     (with_artists, Include::Subquery(Subquery::Artists)),
+    (
+        with_artist_credits,
+        Include::Subquery(Subquery::ArtistCredits)
+    ),
     (with_releases, Include::Subquery(Subquery::Releases)),
+    (
+        with_release_groups,
+        Include::Subquery(Subquery::ReleaseGroups)
+    ),
     (with_tags, Include::Subquery(Subquery::Tags)),
     (with_aliases, Include::Subquery(Subquery::Aliases)),
     (with_genres, Include::Subquery(Subquery::Genres)),
     (with_ratings, Include::Subquery(Subquery::Rating)),
+    (with_user_ratings, Include::Subquery(Subquery::UserRatings)),
     (with_isrcs, Include::Subquery(Subquery::ISRCs)),
+    (
+        with_series_relations,
+        Include::Relationship(Relationship::Series)
+    ),
     (with_url_relations, Include::Relationship(Relationship::Url)),
     (
         with_work_relations,
@@ -167,3 +297,326 @@ impl_includes!(
     ),
     (with_annotations, Include::Subquery(Subquery::Annotations))
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BROWSE_RESULT: &str = r#"
+        {
+            "recording-count": 3,
+            "recording-offset": 0,
+            "recordings": [
+                {
+                    "id": "5fb524f1-8cc8-4c04-a921-e34c0a911ea7",
+                    "title": "Smells Like Teen Spirit",
+                    "video": false,
+                    "length": 301920
+                },
+                {
+                    "id": "1a7a4b8b-9b0b-4d4a-8a9b-357d1bdc1b86",
+                    "title": "Smells Like Teen Spirit",
+                    "video": true,
+                    "length": 279000
+                },
+                {
+                    "id": "af40d6b8-58e8-4ca5-9db8-d4fca0b899e2",
+                    "title": "(New Wave) Polly",
+                    "length": 246000
+                }
+            ]
+        }"#;
+
+    #[test]
+    fn should_exclude_video_recordings() {
+        let result: BrowseResult<Recording> = serde_json::from_str(BROWSE_RESULT).unwrap();
+        let result = result.exclude_videos();
+
+        assert_eq!(result.count, 3);
+        assert_eq!(result.entities.len(), 2);
+        assert!(result
+            .entities
+            .iter()
+            .all(|recording| !recording.is_video()));
+    }
+
+    #[test]
+    fn should_keep_only_video_recordings() {
+        let result: BrowseResult<Recording> = serde_json::from_str(BROWSE_RESULT).unwrap();
+        let result = result.only_videos();
+
+        assert_eq!(result.entities.len(), 1);
+        assert_eq!(
+            result.entities[0].id,
+            "1a7a4b8b-9b0b-4d4a-8a9b-357d1bdc1b86"
+        );
+    }
+
+    #[test]
+    fn should_deserialize_recording_artist_credits() {
+        let input = r#"{
+            "id": "f5f10cee-5d84-41d0-805d-3503872c151d",
+            "title": "Association de gens normal",
+            "video": false,
+            "artist-credit": [
+                {
+                    "name": "TTC",
+                    "joinphrase": " & ",
+                    "artist": {"id": "0f93e9f5-7f2c-4c8b-92c2-f7f8d1b8d5b0", "name": "TTC", "sort-name": "TTC"}
+                },
+                {
+                    "name": "Svinkels",
+                    "joinphrase": "",
+                    "artist": {"id": "d1e3b4e1-0b5a-4c4f-9a0c-8d2a8c8f4a11", "name": "Svinkels", "sort-name": "Svinkels"}
+                }
+            ]
+        }"#;
+
+        let recording: Recording = serde_json::from_str(input).unwrap();
+        let artist_credit = recording.artist_credit.unwrap();
+
+        assert_eq!(artist_credit.len(), 2);
+        assert_eq!(artist_credit[0].joinphrase.as_deref(), Some(" & "));
+        assert_eq!(
+            artist_credit[1].artist.id,
+            "d1e3b4e1-0b5a-4c4f-9a0c-8d2a8c8f4a11"
+        );
+    }
+
+    #[test]
+    fn should_pick_canonical_release() {
+        let release = |title: &str, status: &str, date: &str, types: &[&str]| {
+            serde_json::json!({
+                "id": "1b022e01-4da6-387b-8658-8678046e4cef",
+                "title": title,
+                "status": status,
+                "date": date,
+                "release-group": {
+                    "id": "1b022e01-4da6-387b-8658-8678046e4cef",
+                    "title": title,
+                    "primary-type": "Album",
+                    "secondary-types": types
+                }
+            })
+        };
+        let recording: Recording = serde_json::from_value(serde_json::json!({
+            "id": "5fb524f1-8cc8-4c04-a921-e34c0a911ea7",
+            "title": "Smells Like Teen Spirit",
+            "releases": [
+                release("Nirvana", "Official", "2002-10-29", &["Compilation"]),
+                release("Nevermind", "Bootleg", "1991-01-01", &[]),
+                release("Nevermind", "Official", "", &[]),
+                release("Nevermind", "Official", "1991-09-24", &[]),
+                release("Nevermind (20th anniversary)", "Official", "2011-09-23", &[]),
+            ]
+        }))
+        .unwrap();
+
+        let canonical = recording.canonical_release().unwrap();
+
+        assert_eq!(canonical.title, "Nevermind");
+        assert_eq!(canonical.date, NaiveDate::from_ymd_opt(1991, 9, 24));
+    }
+
+    #[test]
+    fn should_have_no_canonical_release_without_releases() {
+        let recording: Recording = serde_json::from_value(serde_json::json!({
+            "id": "5fb524f1-8cc8-4c04-a921-e34c0a911ea7",
+            "title": "Smells Like Teen Spirit"
+        }))
+        .unwrap();
+
+        assert_eq!(recording.canonical_release(), None);
+    }
+
+    #[test]
+    fn should_tell_original_release_from_reissue() {
+        let recording: Recording = serde_json::from_str(
+            r#"{"id": "5fb524f1-8cc8-4c04-a921-e34c0a911ea7", "title": "Smells Like Teen Spirit",
+                "first-release-date": "1991-09-10"}"#,
+        )
+        .unwrap();
+        let release = |date: &str| -> Release {
+            serde_json::from_str(&format!(
+                r#"{{"id": "r1", "title": "Nevermind", "date": "{}"}}"#,
+                date
+            ))
+            .unwrap()
+        };
+
+        assert!(recording.is_original_on(&release("1991-09-10")));
+        assert!(recording.is_original_on(&release("1991")));
+        assert!(!recording.is_original_on(&release("2002-10-29")));
+        assert!(!recording.is_original_on(&release("")));
+    }
+
+    const COLLECTION_MBID: &str = "f6c4dd8e-bb9e-4b1c-b4b2-8a6e1d1c3f9a";
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn should_browse_recordings_by_collection() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/recording")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "collection".into(),
+                COLLECTION_MBID.into(),
+            ))
+            .with_body(BROWSE_RESULT)
+            .create();
+
+        let result = crate::browse_query_at::<Recording>(&server.url())
+            .by_collection(COLLECTION_MBID)
+            .execute()
+            .unwrap();
+
+        mock.assert();
+        assert_eq!(result.count, 3);
+        assert_eq!(result.entities[2].title, "(New Wave) Polly");
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_browse_recordings_by_collection() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/recording")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "collection".into(),
+                COLLECTION_MBID.into(),
+            ))
+            .with_body(BROWSE_RESULT)
+            .create_async()
+            .await;
+
+        let result = crate::browse_query_at::<Recording>(&server.url())
+            .by_collection(COLLECTION_MBID)
+            .execute()
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(result.count, 3);
+        assert_eq!(result.entities[2].title, "(New Wave) Polly");
+    }
+
+    #[test]
+    fn should_authenticate_collection_browse() {
+        let mut query = crate::browse_query_at::<Recording>("http://localhost");
+        assert!(!query.inner.needs_auth());
+
+        query.by_collection(COLLECTION_MBID);
+        assert!(query.inner.needs_auth());
+    }
+
+    #[test]
+    fn should_authenticate_user_ratings() {
+        let mut query = crate::fetch_query_at::<Recording>("http://localhost");
+        assert!(!query.0.needs_auth());
+
+        query.with_user_ratings();
+        assert!(query.0.needs_auth());
+    }
+
+    const RELEASES_WITH_GENRES: &str = r#"{
+        "id": "5fb524f1-8cc8-4c04-a921-e34c0a911ea7",
+        "title": "Smells Like Teen Spirit",
+        "genres": [],
+        "releases": [
+            {
+                "id": "b52a8f31-b5ab-34e9-92f4-f5b7110220f0",
+                "title": "Nevermind",
+                "genres": [
+                    {"count": 12, "name": "grunge"},
+                    {"count": 4, "name": "alternative rock"}
+                ]
+            },
+            {
+                "id": "2d6a4b6c-b1bf-4bce-8d5e-2e1f9a6c2b53",
+                "title": "Nirvana",
+                "release-group": {
+                    "id": "1b022e01-4da6-387b-8658-8678046e4cef",
+                    "title": "Nirvana",
+                    "genres": [
+                        {"count": 3, "name": "alternative rock"},
+                        {"count": 2, "name": "rock"}
+                    ]
+                }
+            },
+            {
+                "id": "4f4f6f1e-7a4c-4d3a-9f5b-0a1b2c3d4e5f",
+                "title": "Smells Like Teen Spirit"
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn should_fall_back_to_release_genres() {
+        let recording: Recording = serde_json::from_str(RELEASES_WITH_GENRES).unwrap();
+
+        let genres = recording.genres_or_release_genres();
+
+        assert_eq!(
+            genres,
+            vec![
+                Genre {
+                    count: 12,
+                    name: "grunge".to_string()
+                },
+                Genre {
+                    count: 7,
+                    name: "alternative rock".to_string()
+                },
+                Genre {
+                    count: 2,
+                    name: "rock".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn should_prefer_recording_genres() {
+        let mut recording: Recording = serde_json::from_str(RELEASES_WITH_GENRES).unwrap();
+        recording.genres = Some(vec![Genre {
+            count: 1,
+            name: "punk".to_string(),
+        }]);
+
+        let genres = recording.genres_or_release_genres();
+
+        assert_eq!(genres.len(), 1);
+        assert_eq!(genres[0].name, "punk");
+    }
+
+    #[test]
+    fn should_request_recording_genres() {
+        use crate::Fetch;
+
+        let mut query = Recording::fetch();
+        query
+            .id("5fb524f1-8cc8-4c04-a921-e34c0a911ea7")
+            .with_releases()
+            .with_genres();
+
+        query.params_to_path();
+
+        assert!(query.0.path.ends_with("&inc=releases+genres"));
+    }
+
+    #[test]
+    fn should_request_all_relations() {
+        use crate::Fetch;
+
+        let mut query = Recording::fetch();
+        query
+            .id("5fb524f1-8cc8-4c04-a921-e34c0a911ea7")
+            .with_all_relations();
+
+        assert!(query.url().ends_with(
+            "?fmt=json&inc=area-rels+artist-rels+event-rels+instrument-rels+label-rels\
+             +place-rels+recording-rels+release-rels+release-group-rels+series-rels+url-rels\
+             +work-rels+work-level-rels"
+        ));
+    }
+}
@@ -3,6 +3,7 @@ use crate::entity::area::Area;
 use crate::entity::artist::Artist;
 use crate::entity::event::Event;
 use crate::entity::label::Label;
+use crate::entity::lifespan::LifeSpan;
 use crate::entity::place::Place;
 use crate::entity::recording::Recording;
 use crate::entity::release::Release;
@@ -52,6 +53,187 @@ pub struct Relation {
     pub direction: String,
     #[serde(rename = "type")]
     pub relation_type: String,
+    /// The position of the entity in the series, for the relations of a series (e.g. a release
+    /// group part of a numbered series). Use [`Relation::ordering_key_typed`] to sort on it.
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_ordering_key")]
+    pub ordering_key: Option<String>,
+}
+
+/// MusicBrainz sends the ordering key as a number, but it is kept as a string like the
+/// series numbers it mirrors.
+fn deserialize_ordering_key<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let key: Option<serde_json::Value> = Option::deserialize(deserializer)?;
+    Ok(match key {
+        Some(serde_json::Value::String(key)) => Some(key),
+        Some(serde_json::Value::Number(key)) => Some(key.to_string()),
+        _ => None,
+    })
+}
+
+/// A sort key for the position of an entity in a series. Numeric keys sort numerically, so `2`
+/// comes before `10`, and before any non-numeric key, which sort lexically.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub enum OrderingKey {
+    Number(u64),
+    Text(String),
+}
+
+impl OrderingKey {
+    pub fn parse(key: &str) -> OrderingKey {
+        match key.trim().parse() {
+            Ok(number) => OrderingKey::Number(number),
+            Err(_) => OrderingKey::Text(key.to_string()),
+        }
+    }
+}
+
+impl Relation {
+    /// The period during which the relationship applied, e.g. the years an artist was a member
+    /// of a band, as a [`LifeSpan`].
+    pub fn life_span(&self) -> LifeSpan {
+        LifeSpan {
+            ended: self.ended,
+            begin: self.begin,
+            end: self.end,
+        }
+    }
+
+    /// The [`ordering_key`](Relation::ordering_key) as an [`OrderingKey`], to sort the
+    /// relations of a series in order.
+    pub fn ordering_key_typed(&self) -> Option<OrderingKey> {
+        self.ordering_key.as_deref().map(OrderingKey::parse)
+    }
+
+    /// The relationship [`attributes`](Relation::attributes) as [`RelationAttribute`]s.
+    /// Returns an empty `Vec` when the relation has no attributes.
+    pub fn attributes_typed(&self) -> Vec<RelationAttribute> {
+        self.attributes
+            .iter()
+            .flatten()
+            .map(|attribute| RelationAttribute::from(attribute.as_str()))
+            .collect()
+    }
+
+    /// The external link of a url relationship, e.g. a Bandcamp or Wikidata page. Returns
+    /// `None` for relationships to other entities.
+    pub fn url_resource(&self) -> Option<&str> {
+        match &self.content {
+            RelationContent::Url(url) => Some(&url.resource),
+            _ => None,
+        }
+    }
+
+    /// How the recording performs the work, for a "performance" relationship between a recording
+    /// and a work. Returns `None` for any other relationship.
+    pub fn performance_attributes(&self) -> Option<PerformanceAttributes> {
+        if self.relation_type != "performance" {
+            return None;
+        }
+
+        let attributes = self.attributes_typed();
+        let has = |attribute: RelationAttribute| attributes.contains(&attribute);
+        Some(PerformanceAttributes {
+            live: has(RelationAttribute::Live),
+            partial: has(RelationAttribute::Partial),
+            cover: has(RelationAttribute::Cover),
+            instrumental: has(RelationAttribute::Instrumental),
+            medley: has(RelationAttribute::Medley),
+        })
+    }
+}
+
+/// The attributes of a "performance" relationship, telling e.g. a live cover apart from a studio
+/// recording by the original artist. See [`Relation::performance_attributes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PerformanceAttributes {
+    /// The recording is a live performance of the work.
+    pub live: bool,
+    /// The recording only contains part of the work.
+    pub partial: bool,
+    /// The recording is a cover of the work, performed by another artist than the original one.
+    pub cover: bool,
+    /// The recording is an instrumental version of a work with lyrics.
+    pub instrumental: bool,
+    /// The work is performed as part of a medley.
+    pub medley: bool,
+}
+
+/// The most common relationship attributes. Anything else is kept as is in
+/// [`RelationAttribute::Other`].
+///
+/// See the [list of all attributes](https://musicbrainz.org/relationship-attributes).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RelationAttribute {
+    Guitar,
+    BassGuitar,
+    Drums,
+    Piano,
+    Keyboard,
+    Vocal,
+    LeadVocals,
+    BackgroundVocals,
+    Additional,
+    Guest,
+    Solo,
+    Cover,
+    Live,
+    Partial,
+    Medley,
+    Instrumental,
+    Other(String),
+}
+
+impl RelationAttribute {
+    /// The attribute name, as written by MusicBrainz.
+    pub fn as_str(&self) -> &str {
+        match self {
+            RelationAttribute::Guitar => "guitar",
+            RelationAttribute::BassGuitar => "bass guitar",
+            RelationAttribute::Drums => "drums (drum set)",
+            RelationAttribute::Piano => "piano",
+            RelationAttribute::Keyboard => "keyboard",
+            RelationAttribute::Vocal => "vocal",
+            RelationAttribute::LeadVocals => "lead vocals",
+            RelationAttribute::BackgroundVocals => "background vocals",
+            RelationAttribute::Additional => "additional",
+            RelationAttribute::Guest => "guest",
+            RelationAttribute::Solo => "solo",
+            RelationAttribute::Cover => "cover",
+            RelationAttribute::Live => "live",
+            RelationAttribute::Partial => "partial",
+            RelationAttribute::Medley => "medley",
+            RelationAttribute::Instrumental => "instrumental",
+            RelationAttribute::Other(attribute) => attribute,
+        }
+    }
+}
+
+impl From<&str> for RelationAttribute {
+    fn from(attribute: &str) -> Self {
+        match attribute {
+            "guitar" => RelationAttribute::Guitar,
+            "bass guitar" => RelationAttribute::BassGuitar,
+            "drums (drum set)" => RelationAttribute::Drums,
+            "piano" => RelationAttribute::Piano,
+            "keyboard" => RelationAttribute::Keyboard,
+            "vocal" => RelationAttribute::Vocal,
+            "lead vocals" => RelationAttribute::LeadVocals,
+            "background vocals" => RelationAttribute::BackgroundVocals,
+            "additional" => RelationAttribute::Additional,
+            "guest" => RelationAttribute::Guest,
+            "solo" => RelationAttribute::Solo,
+            "cover" => RelationAttribute::Cover,
+            "live" => RelationAttribute::Live,
+            "partial" => RelationAttribute::Partial,
+            "medley" => RelationAttribute::Medley,
+            "instrumental" => RelationAttribute::Instrumental,
+            other => RelationAttribute::Other(other.to_string()),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
@@ -70,3 +252,174 @@ pub enum RelationContent {
     Url(Box<Url>),
     Work(Box<Work>),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_type_relation_attributes() {
+        let json = r#"{
+            "type": "instrument",
+            "type-id": "59054b12-01ac-43ee-a618-285fd397e461",
+            "direction": "backward",
+            "target-type": "artist",
+            "attributes": ["guitar", "lead vocals", "additional", "kazoo"],
+            "attribute-values": {},
+            "attribute-ids": {},
+            "begin": null,
+            "end": null,
+            "ended": false,
+            "source-credit": "",
+            "target-credit": "",
+            "artist": {
+                "id": "5b11f4ce-a62d-471e-81fc-a69a8278c7da",
+                "name": "Nirvana",
+                "sort-name": "Nirvana",
+                "disambiguation": "90s US grunge band"
+            }
+        }"#;
+
+        let relation: Relation = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            relation.attributes_typed(),
+            vec![
+                RelationAttribute::Guitar,
+                RelationAttribute::LeadVocals,
+                RelationAttribute::Additional,
+                RelationAttribute::Other("kazoo".to_string()),
+            ]
+        );
+        assert_eq!(relation.attributes.unwrap()[3], "kazoo");
+        assert_eq!(RelationAttribute::LeadVocals.as_str(), "lead vocals");
+    }
+
+    #[test]
+    fn should_read_membership_period() {
+        let json = r#"{
+            "type": "member of band",
+            "type-id": "5be4c609-9afa-4ea0-910b-12ffb71e3821",
+            "direction": "forward",
+            "target-type": "artist",
+            "attributes": ["drums"],
+            "begin": "1990-09",
+            "end": "1994-04-05",
+            "ended": true,
+            "artist": {
+                "id": "5b11f4ce-a62d-471e-81fc-a69a8278c7da",
+                "name": "Nirvana",
+                "sort-name": "Nirvana",
+                "disambiguation": "90s US grunge band"
+            }
+        }"#;
+
+        let relation: Relation = serde_json::from_str(json).unwrap();
+        let life_span = relation.life_span();
+
+        assert_eq!(life_span.begin, NaiveDate::from_ymd_opt(1990, 9, 1));
+        assert_eq!(life_span.end, NaiveDate::from_ymd_opt(1994, 4, 5));
+        assert_eq!(life_span.ended, Some(true));
+    }
+
+    #[test]
+    fn should_sort_series_relations_numerically() {
+        let relation = |key: &str, title: &str| -> Relation {
+            serde_json::from_str(&format!(
+                r#"{{
+                    "type": "part of",
+                    "type-id": "01018437-91d8-36b9-bf89-3f885d53b5bd",
+                    "direction": "backward",
+                    "target-type": "work",
+                    "ordering-key": {},
+                    "work": {{"id": "0fb9a4b3-5a4e-4a4e-9e5c-2d4b0f6c2f1e", "title": "{}"}}
+                }}"#,
+                key, title
+            ))
+            .unwrap()
+        };
+        let mut relations = [
+            relation("10", "Symphony No. 10"),
+            relation("\"2\"", "Symphony No. 2"),
+            relation("\"bonus\"", "Unfinished Symphony"),
+        ];
+
+        relations.sort_by_key(Relation::ordering_key_typed);
+
+        let keys: Vec<_> = relations
+            .iter()
+            .map(|relation| relation.ordering_key.as_deref().unwrap())
+            .collect();
+        assert_eq!(keys, vec!["2", "10", "bonus"]);
+        assert_eq!(OrderingKey::parse("10"), OrderingKey::Number(10));
+        assert_eq!(
+            OrderingKey::parse("10a"),
+            OrderingKey::Text("10a".to_string())
+        );
+    }
+
+    #[test]
+    fn should_type_performance_attributes() {
+        let relation = |relation_type: &str| -> Relation {
+            serde_json::from_str(&format!(
+                r#"{{
+                    "type": "{}",
+                    "type-id": "a3005666-a872-32c3-ad06-98af558e99b0",
+                    "direction": "forward",
+                    "target-type": "work",
+                    "attributes": ["live", "partial"],
+                    "attribute-values": {{}},
+                    "attribute-ids": {{
+                        "live": "70007db6-a8bc-46d7-a770-80e6a0bb551a",
+                        "partial": "d2b63be6-91ec-426a-987a-30b47f8aae2d"
+                    }},
+                    "work": {{"id": "5d3b6fd8-22bc-3ae5-a5b5-f7c3b9a3ddfa", "title": "Smells Like Teen Spirit"}}
+                }}"#,
+                relation_type
+            ))
+            .unwrap()
+        };
+
+        assert_eq!(
+            relation("performance").performance_attributes(),
+            Some(PerformanceAttributes {
+                live: true,
+                partial: true,
+                ..Default::default()
+            })
+        );
+        assert_eq!(relation("medley").performance_attributes(), None);
+    }
+
+    #[test]
+    fn should_read_url_relation_resources() {
+        let relations: Vec<Relation> = serde_json::from_str(
+            r#"[
+                {"type": "bandcamp", "type-id": "c550166e-0548-4a18-b1d4-e2ae423a3e88",
+                 "direction": "forward", "target-type": "url",
+                 "url": {"id": "1ae5b1ad-f8dd-4e7b-b4b4-3e6b8f3a5d20",
+                         "resource": "https://nirvana.bandcamp.com/"}},
+                {"type": "wikidata", "type-id": "689870a4-a1e4-4912-b17f-7b2664215698",
+                 "direction": "forward", "target-type": "url",
+                 "url": {"id": "4bd4e8e4-1e7a-4c2c-a3a5-5c2f0e9e7e0a",
+                         "resource": "https://www.wikidata.org/wiki/Q11649"}},
+                {"type": "member of band", "type-id": "5be4c609-9afa-4ea0-910b-12ffb71e3821",
+                 "direction": "backward", "target-type": "artist",
+                 "artist": {"id": "5b11f4ce-a62d-471e-81fc-a69a8278c7da", "name": "Nirvana",
+                            "sort-name": "Nirvana"}}
+            ]"#,
+        )
+        .unwrap();
+
+        let resources: Vec<_> = relations.iter().map(Relation::url_resource).collect();
+
+        assert_eq!(
+            resources,
+            vec![
+                Some("https://nirvana.bandcamp.com/"),
+                Some("https://www.wikidata.org/wiki/Q11649"),
+                None,
+            ]
+        );
+    }
+}
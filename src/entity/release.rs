@@ -1,18 +1,27 @@
 use chrono::NaiveDate;
 use lucene_query_builder::QueryBuilder;
 use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use std::fmt;
 
 use super::{Include, Relationship, Subquery};
 use crate::date_format;
 use crate::entity::alias::Alias;
+use crate::entity::area::Area;
 use crate::entity::artist_credit::ArtistCredit;
+use crate::entity::disc::Disc;
 use crate::entity::genre::Genre;
 use crate::entity::label::LabelInfo;
+use crate::entity::mbid::Mbid;
 use crate::entity::recording::Recording;
 use crate::entity::relations::Relation;
-use crate::entity::release_group::ReleaseGroup;
+use crate::entity::release_group::{ReleaseGroup, ReleaseGroupPrimaryType};
 use crate::entity::tag::Tag;
-use crate::entity::BrowseBy;
+use crate::entity::{BrowseBy, BrowseResult};
+#[cfg(feature = "submission")]
+use crate::submission::{self, SubmissionError, SubmissionResponse};
+use crate::Error;
+use crate::Fetch;
 
 /// A MusicBrainz release represents the unique release (i.e. issuing) of a product on a specific
 /// date with specific release information such as the country, label, barcode and packaging.
@@ -34,7 +43,7 @@ use crate::entity::BrowseBy;
 #[serde(rename_all(deserialize = "kebab-case"))]
 pub struct Release {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
 
     /// The title of the release.
     pub title: String,
@@ -53,8 +62,17 @@ pub struct Release {
     /// The country the release was issued in.
     pub country: Option<String>,
 
+    /// Every country and date the release was issued in. `date` and `country` only hold one of
+    /// them.
+    pub release_events: Option<Vec<ReleaseEvent>>,
+
     ///  Data quality indicates how good the data for a release is. It is not a mark of how good or
     /// bad the music itself is - for that, use ratings.
+    /// Releases returned by a search don't carry it, see [`Release::hydrate`].
+    ///
+    /// `None` when MusicBrainz didn't send a quality (absent or `null`). A quality that was never
+    /// set is sent as `"unknown"` and is `Some(ReleaseQuality::Unknown)`, like any quality this
+    /// crate doesn't know.
     pub quality: Option<ReleaseQuality>,
 
     /// The barcode, if the release has one. The most common types found on releases are 12-digit
@@ -70,8 +88,14 @@ pub struct Release {
 
     /// The physical packaging that accompanies the release. See the
     /// [list of packaging](https://musicbrainz.org/doc/Release/Packaging) for more information.
+    ///
+    /// `None` when the packaging is not set, while `Some(ReleasePackaging::None)` means the
+    /// release was explicitly entered as having no packaging.
     pub packaging: Option<ReleasePackaging>,
 
+    /// The language and script the release's track list is written in.
+    pub text_representation: Option<ReleaseTextRepresentation>,
+
     pub relations: Option<Vec<Relation>>,
     /// The release group associated with this release.
     pub release_group: Option<ReleaseGroup>,
@@ -91,31 +115,451 @@ pub struct Release {
     pub annotation: Option<String>,
 }
 
+/// A date and area where a release was issued. Worldwide releases are issued in the "\[Worldwide\]"
+/// area.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct ReleaseEvent {
+    #[serde(deserialize_with = "date_format::deserialize_opt")]
+    #[serde(default)]
+    pub date: Option<NaiveDate>,
+    /// The area the release was issued in. Only a thin area is returned along with the release:
+    /// its id, names and ISO 3166-1 codes.
+    pub area: Option<Area>,
+}
+
+impl ReleaseEvent {
+    /// The ISO 3166-1 code of the event area, e.g. "GB", or "XW" for "\[Worldwide\]". `None` if
+    /// the event has no area, or its area isn't a country.
+    pub fn country_code(&self) -> Option<&str> {
+        self.area.as_ref()?.country_code()
+    }
+}
+
+impl Release {
+    /// The earliest date of the release events, or `date` if no release event is dated.
+    pub fn earliest_release_date(&self) -> Option<NaiveDate> {
+        self.release_events
+            .iter()
+            .flatten()
+            .filter_map(|event| event.date)
+            .min()
+            .or(self.date)
+    }
+
+    /// The ISO 3166-1 codes of the countries the release was issued in, from its release events,
+    /// without duplicates and in the order of the events.
+    pub fn release_country_codes(&self) -> Vec<&str> {
+        let mut codes = vec![];
+        for code in self
+            .release_events
+            .iter()
+            .flatten()
+            .filter_map(ReleaseEvent::country_code)
+        {
+            if !codes.contains(&code) {
+                codes.push(code);
+            }
+        }
+        codes
+    }
+
+    /// The language the track list is written in, from the
+    /// [`text_representation`](Release::text_representation).
+    pub fn title_language(&self) -> Option<Language> {
+        self.text_representation.as_ref()?.language.clone()
+    }
+
+    /// The script the track list is written in, from the
+    /// [`text_representation`](Release::text_representation), e.g. to pick a font for CJK or
+    /// right-to-left titles.
+    pub fn title_script(&self) -> Option<ReleaseScript> {
+        self.text_representation.as_ref()?.script.clone()
+    }
+
+    /// Returns `true` if any of the loaded media has the given format (e.g. `"CD"`, `"12\" Vinyl"`),
+    /// ignoring case.
+    pub fn has_format(&self, format: &str) -> bool {
+        self.media.iter().flatten().any(|media| {
+            media.format.as_ref().map_or(false, |media_format| {
+                media_format.as_str().eq_ignore_ascii_case(format)
+            })
+        })
+    }
+
+    /// Returns `true` if every loaded medium is [`MediaFormat::DigitalMedia`]. Releases mixing
+    /// digital and physical media, or without any loaded media, are not digital.
+    pub fn is_digital(&self) -> bool {
+        let mut media = self.media.iter().flatten().peekable();
+        media.peek().is_some() && media.all(|media| media.format == Some(MediaFormat::DigitalMedia))
+    }
+
+    /// The title of each medium, in position order as given by [`Release::media_ordered`].
+    /// Untitled media are `None`, so the result lines up with the ordered media.
+    pub fn disc_titles(&self) -> Vec<Option<String>> {
+        self.media_ordered()
+            .into_iter()
+            .map(|media| media.title.clone().filter(|title| !title.is_empty()))
+            .collect()
+    }
+
+    /// The loaded media sorted by position. MusicBrainz usually returns them in order, but box
+    /// sets sometimes come back shuffled. Media without a position come last, in their original
+    /// order.
+    pub fn media_ordered(&self) -> Vec<&Media> {
+        let mut media: Vec<&Media> = self.media.iter().flatten().collect();
+        media.sort_by_key(|media| (media.position.is_none(), media.position));
+        media
+    }
+
+    /// Every loaded track of the release, media in position order. Each medium lists its pregap
+    /// first, then its tracks, then its data tracks, so exporters can number them apart.
+    pub fn flat_tracklist(&self) -> Vec<FlatTrack<'_>> {
+        let mut tracklist = vec![];
+        for media in self.media_ordered() {
+            let tracks = media
+                .pregap
+                .iter()
+                .map(|track| (track, TrackKind::Pregap))
+                .chain(
+                    media
+                        .tracks
+                        .iter()
+                        .flatten()
+                        .map(|track| (track, TrackKind::Audio)),
+                )
+                .chain(
+                    media
+                        .data_tracks
+                        .iter()
+                        .flatten()
+                        .map(|track| (track, TrackKind::Data)),
+                );
+            tracklist.extend(tracks.map(|(track, kind)| FlatTrack { media, track, kind }));
+        }
+        tracklist
+    }
+
+    /// Returns `true` if the tracklist of any of the loaded media is truncated.
+    /// See [`Media::is_tracklist_truncated`].
+    pub fn has_truncated_tracklist(&self) -> bool {
+        self.media
+            .iter()
+            .flatten()
+            .any(Media::is_tracklist_truncated)
+    }
+
+    /// Pick the release best representing a set of releases, e.g. "the album a song is from":
+    /// official releases first, then releases of a release group that is a plain album (no
+    /// secondary type such as compilation or live), then the earliest release. Undated releases
+    /// come last, and ties keep the first release.
+    pub fn canonical<'a>(releases: impl IntoIterator<Item = &'a Release>) -> Option<&'a Release> {
+        releases.into_iter().min_by_key(|release| {
+            let is_official = release.status == Some(ReleaseStatus::Official);
+            let is_album = release.release_group.as_ref().map_or(false, |group| {
+                group.primary_type == Some(ReleaseGroupPrimaryType::Album)
+                    && group.secondary_types.is_empty()
+            });
+            (
+                !is_official,
+                !is_album,
+                release.date.is_none(),
+                release.date,
+            )
+        })
+    }
+
+    /// The number of loaded media, or `0` if they weren't requested.
+    pub fn medium_count(&self) -> usize {
+        self.media.as_ref().map_or(0, Vec::len)
+    }
+
+    /// The number of disc IDs attached to all the media of the release. Uses `disc_count` when
+    /// MusicBrainz sent it, and counts the loaded [`discs`](Media::discs) otherwise.
+    pub fn total_disc_count(&self) -> u32 {
+        self.media
+            .iter()
+            .flatten()
+            .map(|media| media.disc_count.unwrap_or(media.disc_count_actual() as u32))
+            .sum()
+    }
+}
+
+impl Release {
+    /// Fetch the full release by its MBID.
+    ///
+    /// Releases returned by a search are trimmed by MusicBrainz: they carry `status`,
+    /// `packaging` and `country`, but not `quality`.
+    #[cfg(feature = "blocking")]
+    pub fn hydrate(&self) -> Result<Release, Error> {
+        Release::fetch().id(&self.id).execute()
+    }
+
+    /// Fetch the full release by its MBID.
+    ///
+    /// Releases returned by a search are trimmed by MusicBrainz: they carry `status`,
+    /// `packaging` and `country`, but not `quality`.
+    #[cfg(feature = "async")]
+    pub async fn hydrate(&self) -> Result<Release, Error> {
+        Release::fetch().id(&self.id).execute().await
+    }
+}
+
+#[cfg(all(feature = "submission", feature = "blocking"))]
+impl Release {
+    /// Submit the barcode of the release with the given MBID. The barcode is validated before
+    /// anything is sent. See [`crate::submission`].
+    pub fn submit_barcode(
+        mbid: &str,
+        barcode: &str,
+    ) -> Result<SubmissionResponse, SubmissionError> {
+        let body = submission::barcode_submission_body(mbid, barcode)?;
+        submission::submit(&crate::config::base_url(), "release", body)
+    }
+}
+
+#[cfg(all(feature = "submission", feature = "async"))]
+impl Release {
+    /// Submit the barcode of the release with the given MBID. The barcode is validated before
+    /// anything is sent. See [`crate::submission`].
+    pub async fn submit_barcode(
+        mbid: &str,
+        barcode: &str,
+    ) -> Result<SubmissionResponse, SubmissionError> {
+        let body = submission::barcode_submission_body(mbid, barcode)?;
+        submission::submit(&crate::config::base_url(), "release", body).await
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 pub struct ReleaseTextRepresentation {
     /// The language a release's track list is written in. The possible values are taken from the ISO
     /// 639-3 standard.
-    pub language: Language,
+    pub language: Option<Language>,
     /// The script used to write the release's track list. The possible values are taken from the
     /// ISO 15924 standard.
-    pub script: ReleaseScript,
+    pub script: Option<ReleaseScript>,
 }
 
 /// The script used to write the release's track list. The possible values are taken from the
 /// [ISO 15924](https://en.wikipedia.org/wiki/ISO_15924) standard.
+/// Note that this enum is `non_exhaustive`; only the most frequently used scripts have a variant.
+#[non_exhaustive]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "tracing", serde(remote = "Self"))]
 pub enum ReleaseScript {
     /* TODO: we need to test all posible values to build the enum see https://musicbrainz.org/doc/Release */
     /// ## Latin (also known as Roman or, incorrectly, "English")
     /// Latin is the most common script, and usually the correct choice. It is used
     /// for all Western European languages, and many others. It is also the most common script used for transliterations.
     Latn,
+    /// ## Cyrillic
+    /// Cyrillic is used for Russian, Ukrainian, Bulgarian, Serbian and many other languages of
+    /// Eastern Europe and Central Asia.
+    Cyrl,
+    /// ## Greek
+    /// Greek is used for the Greek language.
+    Grek,
+    /// ## Japanese
+    /// Japanese is used for releases which mix the Han, Hiragana and Katakana scripts, which is the
+    /// case of most Japanese releases.
+    Jpan,
+    /// ## Han (Simplified variant)
+    /// Simplified Han is used for Chinese releases from mainland China and Singapore.
+    Hans,
+    /// ## Han (Traditional variant)
+    /// Traditional Han is used for Chinese releases from Taiwan, Hong Kong and Macau.
+    Hant,
+    /// ## Hiragana
+    /// Hiragana is used for Japanese releases written only in hiragana.
+    Hira,
+    /// ## Katakana
+    /// Katakana is used for Japanese releases written only in katakana.
+    Kana,
+    /// ## Hangul
+    /// Hangul is used for Korean releases written only in Hangul.
+    Hang,
+    /// ## Korean
+    /// Korean is used for Korean releases which mix the Hangul and Han scripts.
+    Kore,
+    /// ## Arabic
+    /// Arabic is used for Arabic, Persian, Urdu and several other languages.
+    Arab,
+    /// ## Hebrew
+    /// Hebrew is used for Hebrew and Yiddish.
+    Hebr,
+    /// ## Thai
+    /// Thai is used for the Thai language.
+    Thai,
+    /// ## Devanagari
+    /// Devanagari is used for Hindi, Marathi, Nepali and many other languages of India.
+    Deva,
+    /// ## Tamil
+    /// Tamil is used for the Tamil language.
+    Taml,
+    /// ## Armenian
+    /// Armenian is used for the Armenian language.
+    Armn,
+    /// ## Georgian
+    /// Georgian is used for the Georgian language.
+    Geor,
+    /// ## Braille
+    /// Braille is used for releases intended to be read by touch.
+    Brai,
+    /// ## Multiple scripts
+    /// Used when the track list is written in more than one script.
+    Qaaa,
+    /// Any script that does not yet have a corresponding variant in this enum.
+    /// If you ever see a `ReleaseScript::UnrecognizedReleaseScript` in the wild, let us know and file an issue/pull request!
+    #[serde(other)]
+    UnrecognizedReleaseScript,
+}
+
+impl fmt::Display for ReleaseScript {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ReleaseScript::Latn => "Latin",
+            ReleaseScript::Cyrl => "Cyrillic",
+            ReleaseScript::Grek => "Greek",
+            ReleaseScript::Jpan => "Japanese",
+            ReleaseScript::Hans => "Han (Simplified variant)",
+            ReleaseScript::Hant => "Han (Traditional variant)",
+            ReleaseScript::Hira => "Hiragana",
+            ReleaseScript::Kana => "Katakana",
+            ReleaseScript::Hang => "Hangul",
+            ReleaseScript::Kore => "Korean",
+            ReleaseScript::Arab => "Arabic",
+            ReleaseScript::Hebr => "Hebrew",
+            ReleaseScript::Thai => "Thai",
+            ReleaseScript::Deva => "Devanagari",
+            ReleaseScript::Taml => "Tamil",
+            ReleaseScript::Armn => "Armenian",
+            ReleaseScript::Geor => "Georgian",
+            ReleaseScript::Brai => "Braille",
+            ReleaseScript::Qaaa => "Multiple scripts",
+            ReleaseScript::UnrecognizedReleaseScript => "Unrecognized script",
+        };
+
+        f.write_str(name)
+    }
 }
 
-/* TODO: we need to test all posible values to build the enum see https://musicbrainz.org/doc/Release */
+/// The language a release's track list or a work's lyrics are written in. The possible values
+/// are taken from the [ISO 639-3](https://en.wikipedia.org/wiki/ISO_639-3) standard, and are sent
+/// as lowercase codes by MusicBrainz (e.g. "eng").
+/// Note that this enum is `non_exhaustive`; only the most frequently used languages have a variant.
+#[non_exhaustive]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "tracing", serde(remote = "Self"))]
+#[serde(rename_all = "lowercase")]
 pub enum Language {
+    Ara,
+    Ben,
+    Bul,
+    Cat,
+    Ces,
+    Dan,
+    Deu,
+    Ell,
     Eng,
+    Est,
+    Fas,
+    Fin,
+    Fra,
+    Gle,
+    Heb,
+    Hin,
+    Hrv,
+    Hun,
+    Ind,
+    Isl,
+    Ita,
+    Jpn,
+    Kor,
+    Lat,
+    Lav,
+    Lit,
+    Msa,
+    Nld,
+    Nor,
+    Pol,
+    Por,
+    Ron,
+    Rus,
+    Slk,
+    Slv,
+    Spa,
+    Srp,
+    Swe,
+    Tha,
+    Tur,
+    Ukr,
+    Vie,
+    Yid,
+    Zho,
+    /// Used when the tracklist or the lyrics are written in more than one language.
+    Mul,
+    /// Used when there is no linguistic content, e.g. instrumental releases.
+    Zxx,
+    /// Any language that does not yet have a corresponding variant in this enum.
+    /// If you ever see a `Language::UnrecognizedLanguage` in the wild, let us know and file an issue/pull request!
+    #[serde(other)]
+    UnrecognizedLanguage,
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Language::Ara => "Arabic",
+            Language::Ben => "Bengali",
+            Language::Bul => "Bulgarian",
+            Language::Cat => "Catalan",
+            Language::Ces => "Czech",
+            Language::Dan => "Danish",
+            Language::Deu => "German",
+            Language::Ell => "Greek",
+            Language::Eng => "English",
+            Language::Est => "Estonian",
+            Language::Fas => "Persian",
+            Language::Fin => "Finnish",
+            Language::Fra => "French",
+            Language::Gle => "Irish",
+            Language::Heb => "Hebrew",
+            Language::Hin => "Hindi",
+            Language::Hrv => "Croatian",
+            Language::Hun => "Hungarian",
+            Language::Ind => "Indonesian",
+            Language::Isl => "Icelandic",
+            Language::Ita => "Italian",
+            Language::Jpn => "Japanese",
+            Language::Kor => "Korean",
+            Language::Lat => "Latin",
+            Language::Lav => "Latvian",
+            Language::Lit => "Lithuanian",
+            Language::Msa => "Malay",
+            Language::Nld => "Dutch",
+            Language::Nor => "Norwegian",
+            Language::Pol => "Polish",
+            Language::Por => "Portuguese",
+            Language::Ron => "Romanian",
+            Language::Rus => "Russian",
+            Language::Slk => "Slovak",
+            Language::Slv => "Slovenian",
+            Language::Spa => "Spanish",
+            Language::Srp => "Serbian",
+            Language::Swe => "Swedish",
+            Language::Tha => "Thai",
+            Language::Tur => "Turkish",
+            Language::Ukr => "Ukrainian",
+            Language::Vie => "Vietnamese",
+            Language::Yid => "Yiddish",
+            Language::Zho => "Chinese",
+            Language::Mul => "Multiple languages",
+            Language::Zxx => "No linguistic content",
+            Language::UnrecognizedLanguage => "Unrecognized language",
+        };
+
+        f.write_str(name)
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
@@ -132,9 +576,26 @@ pub enum ReleaseQuality {
     /// "normal" if it has.
     Normal,
 
+    None,
+
+    /// The quality has never been set. Qualities that don't have a corresponding variant in
+    /// this enum also map to `Unknown`.
+    #[serde(other)]
     Unknown,
+}
 
-    None,
+impl fmt::Display for ReleaseQuality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ReleaseQuality::Low => "Low",
+            ReleaseQuality::High => "High",
+            ReleaseQuality::Normal => "Normal",
+            ReleaseQuality::None => "None",
+            ReleaseQuality::Unknown => "Unknown",
+        };
+
+        f.write_str(name)
+    }
 }
 
 /// The release status describes how "official" a release is.
@@ -143,6 +604,7 @@ pub enum ReleaseQuality {
 /// Variants are derived from the `release_status` table in the MusicBrainz database.
 #[non_exhaustive]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "tracing", serde(remote = "Self"))]
 pub enum ReleaseStatus {
     /// Any release officially sanctioned by the artist and/or their record company. Most releases
     /// will fit into this category.
@@ -165,12 +627,36 @@ pub enum ReleaseStatus {
     UnrecognizedReleaseStatus,
 }
 
+impl ReleaseStatus {
+    /// The name MusicBrainz gives to the status, e.g. `Official` or `Pseudo-Release`.
+    /// `UnrecognizedReleaseStatus` maps to an empty string since the original value is not kept.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReleaseStatus::Official => "Official",
+            ReleaseStatus::Promotion => "Promotion",
+            ReleaseStatus::Bootleg => "Bootleg",
+            ReleaseStatus::PseudoRelease => "Pseudo-Release",
+            ReleaseStatus::UnrecognizedReleaseStatus => "",
+        }
+    }
+}
+
+impl fmt::Display for ReleaseStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReleaseStatus::UnrecognizedReleaseStatus => f.write_str("Unrecognized status"),
+            status => f.write_str(status.as_str()),
+        }
+    }
+}
+
 /// The type of packaging of a MusicBrainz release entity.
 /// Note that this enum is `non_exhaustive`; The list of release types is subject to change and
 /// these changes are only reflected in the DB, not in actual MB code.
 /// Variants are derived from the `release_packaging` table in the MusicBrainz database.
 #[non_exhaustive]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "tracing", serde(remote = "Self"))]
 pub enum ReleasePackaging {
     Book,
     Box,
@@ -214,16 +700,320 @@ pub enum ReleasePackaging {
     UnrecognizedReleasePackaging,
 }
 
+impl fmt::Display for ReleasePackaging {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ReleasePackaging::Book => "Book",
+            ReleasePackaging::Box => "Box",
+            ReleasePackaging::CardboardPaperSleeve => "Cardboard/Paper Sleeve",
+            ReleasePackaging::CassetteCase => "Cassette Case",
+            ReleasePackaging::Digibook => "Digibook",
+            ReleasePackaging::Digipak => "Digipak",
+            ReleasePackaging::DiscboxSlider => "Discbox Slider",
+            ReleasePackaging::Fatbox => "Fatbox",
+            ReleasePackaging::GatefoldCover => "Gatefold Cover",
+            ReleasePackaging::JewelCase => "Jewel Case",
+            ReleasePackaging::KeepCase => "Keep Case",
+            ReleasePackaging::PlasticSleeve => "Plastic Sleeve",
+            ReleasePackaging::Slidepack => "Slidepack",
+            ReleasePackaging::SlimJewelCase => "Slim Jewel Case",
+            ReleasePackaging::SnapCase => "Snap Case",
+            ReleasePackaging::Snappack => "SnapPack",
+            ReleasePackaging::SuperJewelBox => "Super Jewel Box",
+            ReleasePackaging::Other => "Other",
+            ReleasePackaging::None => "None",
+            ReleasePackaging::UnrecognizedReleasePackaging => "Unrecognized packaging",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// A medium of a release, and its tracklist.
+///
+/// MusicBrainz caps the number of tracks returned in a release lookup, and the web service has no
+/// way to page through the tracks of a single medium. For very large releases (DJ mixes, box sets)
+/// the tracklist can therefore be cut short: use [`Media::is_tracklist_truncated`] to detect it,
+/// and browse the recordings of the release (`Recording::browse().by_release(..)`) to get
+/// them all.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 #[serde(rename_all(deserialize = "kebab-case"))]
 pub struct Media {
     pub title: Option<String>,
     pub position: Option<u32>,
+    /// The total number of tracks on this medium, even when `tracks` is truncated.
     pub track_count: u32,
+    /// The position of the first track of `tracks` in the medium tracklist.
+    pub track_offset: Option<u32>,
     pub disc_count: Option<u32>,
+    /// The MBID of the format.
     pub format_id: Option<String>,
-    pub format: Option<String>,
+    /// The format of this medium. Formats added to MusicBrainz after this crate was released
+    /// deserialize to [`MediaFormat::UnrecognizedMediaFormat`], which keeps their name.
+    pub format: Option<MediaFormat>,
     pub tracks: Option<Vec<Track>>,
+    /// The hidden track before the first track of a CD, at position `0`. It is not part of
+    /// `tracks`.
+    pub pregap: Option<Track>,
+    /// The data tracks of an enhanced CD. They are not part of `tracks`, which only lists the
+    /// audio tracks.
+    pub data_tracks: Option<Vec<Track>>,
+    /// The disc IDs attached to this medium. Only present when the `discids` include is requested.
+    pub discs: Option<Vec<Disc>>,
+}
+
+impl Media {
+    /// Returns `true` if disc IDs were loaded for this medium and at least one is attached to it.
+    pub fn has_discids(&self) -> bool {
+        self.discs.as_ref().map_or(false, |discs| !discs.is_empty())
+    }
+
+    /// The number of disc IDs actually loaded for this medium, or `0` if they weren't requested.
+    /// Unlike `disc_count`, this only counts the discs present in the response.
+    pub fn disc_count_actual(&self) -> usize {
+        self.discs.as_ref().map_or(0, Vec::len)
+    }
+
+    /// Returns `true` if tracks were loaded for this medium but fewer than `track_count` were
+    /// returned, or if they don't start at the first track.
+    pub fn is_tracklist_truncated(&self) -> bool {
+        self.tracks.as_ref().map_or(false, |tracks| {
+            tracks.len() < self.track_count as usize
+                || self.track_offset.map_or(false, |offset| offset > 0)
+        })
+    }
+}
+
+/// A track of [`Release::flat_tracklist`], along with its medium.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlatTrack<'a> {
+    /// The medium the track is on, e.g. to read its position or format.
+    pub media: &'a Media,
+    pub track: &'a Track,
+    pub kind: TrackKind,
+}
+
+impl FlatTrack<'_> {
+    /// Returns `true` for the hidden track before the first track of a CD.
+    pub fn is_pregap(&self) -> bool {
+        self.kind == TrackKind::Pregap
+    }
+}
+
+/// Where a track sits on its medium.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrackKind {
+    /// The hidden track before the first track of a CD, at position `0`. See [`Media::pregap`].
+    Pregap,
+    /// A track of the regular tracklist. See [`Media::tracks`].
+    Audio,
+    /// A data track of an enhanced CD, after the audio tracks. See [`Media::data_tracks`].
+    Data,
+}
+
+/// The format of a medium, e.g. CD or 12" Vinyl.
+/// Note that this enum is `non_exhaustive`; The list of formats is subject to change and these
+/// changes are only reflected in the DB, not in actual MB code.
+/// Variants are derived from the `medium_format` table in the MusicBrainz database.
+#[non_exhaustive]
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum MediaFormat {
+    CD,
+    CDR,
+    EnhancedCD,
+    HDCD,
+    SHMCD,
+    BluSpecCD,
+    EightCmCD,
+    CopyControlCD,
+    DualDisc,
+    SACD,
+    HybridSACD,
+    Vinyl,
+    SevenInchVinyl,
+    TenInchVinyl,
+    TwelveInchVinyl,
+    FlexiDisc,
+    Shellac,
+    Cassette,
+    Microcassette,
+    ReelToReel,
+    EightTrackCartridge,
+    DAT,
+    DCC,
+    MiniDisc,
+    DigitalMedia,
+    DownloadCard,
+    USBFlashDrive,
+    DVD,
+    DVDAudio,
+    DVDVideo,
+    BluRay,
+    HDDVD,
+    VCD,
+    VHS,
+    LaserDisc,
+    Other,
+    /// Any medium format that does not yet have a corresponding variant in this enum, with the
+    /// name MusicBrainz sent for it.
+    /// If you ever see a `MediaFormat::UnrecognizedMediaFormat` in the wild, let us know and file an issue/pull request!
+    UnrecognizedMediaFormat(String),
+}
+
+impl MediaFormat {
+    const ALL: &'static [MediaFormat] = &[
+        MediaFormat::CD,
+        MediaFormat::CDR,
+        MediaFormat::EnhancedCD,
+        MediaFormat::HDCD,
+        MediaFormat::SHMCD,
+        MediaFormat::BluSpecCD,
+        MediaFormat::EightCmCD,
+        MediaFormat::CopyControlCD,
+        MediaFormat::DualDisc,
+        MediaFormat::SACD,
+        MediaFormat::HybridSACD,
+        MediaFormat::Vinyl,
+        MediaFormat::SevenInchVinyl,
+        MediaFormat::TenInchVinyl,
+        MediaFormat::TwelveInchVinyl,
+        MediaFormat::FlexiDisc,
+        MediaFormat::Shellac,
+        MediaFormat::Cassette,
+        MediaFormat::Microcassette,
+        MediaFormat::ReelToReel,
+        MediaFormat::EightTrackCartridge,
+        MediaFormat::DAT,
+        MediaFormat::DCC,
+        MediaFormat::MiniDisc,
+        MediaFormat::DigitalMedia,
+        MediaFormat::DownloadCard,
+        MediaFormat::USBFlashDrive,
+        MediaFormat::DVD,
+        MediaFormat::DVDAudio,
+        MediaFormat::DVDVideo,
+        MediaFormat::BluRay,
+        MediaFormat::HDDVD,
+        MediaFormat::VCD,
+        MediaFormat::VHS,
+        MediaFormat::LaserDisc,
+        MediaFormat::Other,
+    ];
+
+    /// The name MusicBrainz gives to the format, as used by the `format` search field.
+    pub fn as_str(&self) -> &str {
+        match self {
+            MediaFormat::CD => "CD",
+            MediaFormat::CDR => "CD-R",
+            MediaFormat::EnhancedCD => "Enhanced CD",
+            MediaFormat::HDCD => "HDCD",
+            MediaFormat::SHMCD => "SHM-CD",
+            MediaFormat::BluSpecCD => "Blu-spec CD",
+            MediaFormat::EightCmCD => "8cm CD",
+            MediaFormat::CopyControlCD => "Copy Control CD",
+            MediaFormat::DualDisc => "DualDisc",
+            MediaFormat::SACD => "SACD",
+            MediaFormat::HybridSACD => "Hybrid SACD",
+            MediaFormat::Vinyl => "Vinyl",
+            MediaFormat::SevenInchVinyl => "7\" Vinyl",
+            MediaFormat::TenInchVinyl => "10\" Vinyl",
+            MediaFormat::TwelveInchVinyl => "12\" Vinyl",
+            MediaFormat::FlexiDisc => "Flexi-disc",
+            MediaFormat::Shellac => "Shellac",
+            MediaFormat::Cassette => "Cassette",
+            MediaFormat::Microcassette => "Microcassette",
+            MediaFormat::ReelToReel => "Reel-to-reel",
+            MediaFormat::EightTrackCartridge => "8-Track Cartridge",
+            MediaFormat::DAT => "DAT",
+            MediaFormat::DCC => "DCC",
+            MediaFormat::MiniDisc => "MiniDisc",
+            MediaFormat::DigitalMedia => "Digital Media",
+            MediaFormat::DownloadCard => "Download Card",
+            MediaFormat::USBFlashDrive => "USB Flash Drive",
+            MediaFormat::DVD => "DVD",
+            MediaFormat::DVDAudio => "DVD-Audio",
+            MediaFormat::DVDVideo => "DVD-Video",
+            MediaFormat::BluRay => "Blu-ray",
+            MediaFormat::HDDVD => "HD-DVD",
+            MediaFormat::VCD => "VCD",
+            MediaFormat::VHS => "VHS",
+            MediaFormat::LaserDisc => "LaserDisc",
+            MediaFormat::Other => "Other",
+            MediaFormat::UnrecognizedMediaFormat(format) => format,
+        }
+    }
+}
+
+impl Serialize for MediaFormat {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for MediaFormat {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        match MediaFormat::ALL
+            .iter()
+            .find(|format| format.as_str() == raw)
+        {
+            Some(format) => Ok(format.clone()),
+            None => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(value = %raw, "unrecognized MediaFormat from MusicBrainz");
+                Ok(MediaFormat::UnrecognizedMediaFormat(raw))
+            }
+        }
+    }
+}
+
+/// Map a user-entered format, like `12 inch vinyl` or `compact disc`, to the matching
+/// [`MediaFormat`]. The comparison ignores case, spaces and punctuation, and knows a few common
+/// synonyms. Returns `None` when the text matches no format.
+pub fn normalize_format(format: &str) -> Option<MediaFormat> {
+    let key = format_key(format);
+    if key.is_empty() {
+        return None;
+    }
+
+    if let Some(format) = MediaFormat::ALL
+        .iter()
+        .find(|candidate| format_key(candidate.as_str()) == key)
+    {
+        return Some(format.clone());
+    }
+
+    let format = match key.as_str() {
+        "compactdisc" | "audiocd" | "cdda" => MediaFormat::CD,
+        "compactdiscrecordable" => MediaFormat::CDR,
+        "lp" | "record" | "vinylrecord" => MediaFormat::Vinyl,
+        "7in" | "7insingle" | "45" => MediaFormat::SevenInchVinyl,
+        "10in" => MediaFormat::TenInchVinyl,
+        "12in" | "12insingle" | "maxisingle" => MediaFormat::TwelveInchVinyl,
+        "78" | "78rpm" => MediaFormat::Shellac,
+        "tape" | "mc" | "cassettetape" | "compactcassette" => MediaFormat::Cassette,
+        "digital" | "download" | "file" | "mp3" | "flac" | "streaming" | "web" => {
+            MediaFormat::DigitalMedia
+        }
+        "md" => MediaFormat::MiniDisc,
+        "bluraydisc" | "bd" => MediaFormat::BluRay,
+        "superaudiocd" => MediaFormat::SACD,
+        "usb" | "usbstick" => MediaFormat::USBFlashDrive,
+        _ => return None,
+    };
+    Some(format)
+}
+
+/// Lowercase `format` and keep only its letters and digits, reading `"` and `inch` as `in`.
+fn format_key(format: &str) -> String {
+    format
+        .to_lowercase()
+        .replace("inch", "in")
+        .replace(['"', '\u{2033}', '\u{201d}'], "in")
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect()
 }
 
 /// A track is the way a recording is represented on a particular release (or, more exactly, on a
@@ -236,7 +1026,26 @@ pub struct Track {
     pub number: String,
     pub length: Option<u32>,
     pub position: u32,
-    pub id: String,
+    pub id: Mbid,
+    /// The artists credited on this track, which can differ from the recording credits.
+    /// Present when the release is fetched with both
+    /// [`with_recordings`](crate::FetchQuery::with_recordings) and
+    /// [`with_artist_credits`](crate::FetchQuery::with_artist_credits), see
+    /// [`with_recording_credits`](crate::FetchQuery::with_recording_credits).
+    pub artist_credit: Option<Vec<ArtistCredit>>,
+}
+
+impl Track {
+    /// The length to display for this track, in milliseconds: the track's own length, or the
+    /// length of its recording when the track has none.
+    pub fn effective_length(&self) -> Option<u32> {
+        self.length.or(self.recording.length)
+    }
+
+    /// Returns `true` if the recording of this track is a video.
+    pub fn is_video(&self) -> bool {
+        self.recording.is_video()
+    }
 }
 
 #[derive(Debug, Default, Serialize, Deserialize, QueryBuilder)]
@@ -257,7 +1066,7 @@ pub struct ReleaseSearchQuery {
     /// any catalog number for this release (insensitive to case, spaces and separators)
     #[query_builder_field = "catno"]
     catalog_number: String,
-    /// (part of) the release group's disambiguation comment
+    /// (part of) the release's disambiguation comment
     comment: String,
     /// the 2-letter code (ISO 3166-1 alpha-2) for any country the release was released in
     country: String,
@@ -318,6 +1127,10 @@ pub struct ReleaseSearchQuery {
     release_type: String,
 }
 
+impl_try_build!(ReleaseSearchQueryLuceneQueryBuilder);
+
+impl_format_normalized!(ReleaseSearchQueryLuceneQueryBuilder);
+
 impl_browse! {
 Release,
    (by_area, BrowseBy::Area),
@@ -330,6 +1143,159 @@ Release,
    (by_collection, BrowseBy::Collection)
 }
 
+impl crate::BrowseQuery<Release> {
+    /// Only browse releases with the given status, e.g. the official releases a recording
+    /// appears on. The filter is applied by MusicBrainz, so `count` and paging only cover the
+    /// matching releases. Packaging can't be filtered server side.
+    ///
+    /// `UnrecognizedReleaseStatus` is ignored.
+    pub fn status(&mut self, status: ReleaseStatus) -> &mut Self {
+        use std::fmt::Write as _;
+        if status != ReleaseStatus::UnrecognizedReleaseStatus {
+            self.inner.start_params();
+            let _ = write!(
+                self.inner.path,
+                "&status={}",
+                status.as_str().to_lowercase()
+            );
+        }
+        self
+    }
+
+    /// Only browse releases whose release group has the given primary type. Like
+    /// [`BrowseQuery::status`](crate::BrowseQuery::status), the filter is applied by
+    /// MusicBrainz. The medium format can't be filtered server side.
+    ///
+    /// `UnrecognizedReleaseGroupPrimaryType` is ignored.
+    ///
+    /// ## Example
+    ///
+    /// Page through the albums of a label. A label fetched
+    /// [`with_releases`](crate::FetchQuery::with_releases) only lists its first 25 releases.
+    /// ```rust
+    /// # use musicbrainz_rs_nova::prelude::*;
+    /// # use musicbrainz_rs_nova::entity::release::Release;
+    /// # use musicbrainz_rs_nova::entity::release_group::ReleaseGroupPrimaryType;
+    /// # #[cfg(feature = "blocking")]
+    /// # fn main() -> Result<(), Error> {
+    /// let mut offset = 0;
+    /// loop {
+    ///     let page = Release::browse()
+    ///         .by_label("47e718e1-7ee4-460c-b1cc-1192a841c6e5")
+    ///         .release_type(ReleaseGroupPrimaryType::Album)
+    ///         .limit(100)
+    ///         .offset(offset)
+    ///         .execute()?;
+    ///
+    ///     offset += page.entities.len() as u16;
+    ///     if page.entities.is_empty() || offset as i32 >= page.count {
+    ///         break;
+    ///     }
+    /// }
+    /// #   Ok(())
+    /// # }
+    /// # #[cfg(feature = "async")]
+    /// # fn main() {}
+    /// ```
+    pub fn release_type(&mut self, release_type: ReleaseGroupPrimaryType) -> &mut Self {
+        use std::fmt::Write as _;
+        if release_type != ReleaseGroupPrimaryType::UnrecognizedReleaseGroupPrimaryType {
+            self.inner.start_params();
+            let _ = write!(
+                self.inner.path,
+                "&type={}",
+                release_type.as_str().to_lowercase()
+            );
+        }
+        self
+    }
+}
+
+impl crate::BrowseQuery<Release> {
+    /// Browse the releases containing any of `recordings`. MusicBrainz only browses by one
+    /// recording at a time, so [`ReleasesByRecordingsQuery::execute`] browses every page of
+    /// each recording, with the filters and limit set so far, and merges the releases.
+    pub fn by_recordings<S: AsRef<str>>(&self, recordings: &[S]) -> ReleasesByRecordingsQuery {
+        ReleasesByRecordingsQuery {
+            query: self.clone(),
+            recordings: recordings
+                .iter()
+                .map(|recording| recording.as_ref().to_string())
+                .collect(),
+        }
+    }
+}
+
+/// A release browse sent once per recording, see
+/// [`BrowseQuery::by_recordings`](crate::BrowseQuery::by_recordings).
+#[derive(Clone, Debug)]
+pub struct ReleasesByRecordingsQuery {
+    query: crate::BrowseQuery<Release>,
+    recordings: Vec<String>,
+}
+
+impl ReleasesByRecordingsQuery {
+    /// The releases of all the recordings, in the order they were first found. Every page of
+    /// each recording is browsed. A release containing several of the recordings is only
+    /// returned once.
+    #[cfg(feature = "blocking")]
+    pub fn execute(&self) -> Result<Vec<Release>, Error> {
+        let mut pages = vec![];
+        for recording in &self.recordings {
+            let mut offset = Some(self.query.offset.unwrap_or(0));
+            while let Some(page_offset) = offset {
+                let page = self.page(recording, page_offset).execute()?;
+                offset = next_offset(page_offset, &page);
+                pages.push(page);
+            }
+        }
+        Ok(merge_pages(pages))
+    }
+
+    /// The releases of all the recordings, in the order they were first found. Every page of
+    /// each recording is browsed. A release containing several of the recordings is only
+    /// returned once.
+    #[cfg(feature = "async")]
+    pub async fn execute(&self) -> Result<Vec<Release>, Error> {
+        let mut pages = vec![];
+        for recording in &self.recordings {
+            let mut offset = Some(self.query.offset.unwrap_or(0));
+            while let Some(page_offset) = offset {
+                let page = self.page(recording, page_offset).execute().await?;
+                offset = next_offset(page_offset, &page);
+                pages.push(page);
+            }
+        }
+        Ok(merge_pages(pages))
+    }
+
+    fn page(&self, recording: &str, offset: u16) -> crate::BrowseQuery<Release> {
+        let mut query = self.query.clone();
+        query.by_recording(recording).offset(offset);
+        query
+    }
+}
+
+/// The offset of the page after `page`, or `None` once every release of the recording was
+/// browsed.
+fn next_offset(offset: u16, page: &BrowseResult<Release>) -> Option<u16> {
+    let next = usize::from(offset) + page.entities.len();
+    if !page.entities.is_empty() && next < page.count.max(0) as usize {
+        u16::try_from(next).ok()
+    } else {
+        None
+    }
+}
+
+/// The releases of all the `pages`, each one only once, see [`BrowseResult::merge`].
+fn merge_pages(pages: Vec<BrowseResult<Release>>) -> Vec<Release> {
+    let mut pages = pages.into_iter();
+    match pages.next() {
+        Some(first) => first.merge(pages).entities,
+        None => vec![],
+    }
+}
+
 impl_includes!(
     Release,
     (with_artists, Include::Subquery(Subquery::Artists)),
@@ -342,6 +1308,10 @@ impl_includes!(
         with_work_relations,
         Include::Relationship(Relationship::Work)
     ),
+    (
+        with_series_relations,
+        Include::Relationship(Relationship::Series)
+    ),
     (with_url_relations, Include::Relationship(Relationship::Url)),
     (
         with_work_level_relations,
@@ -352,6 +1322,7 @@ impl_includes!(
         Include::Relationship(Relationship::RecordingLevel)
     ),
     (with_recordings, Include::Subquery(Subquery::Recordings)),
+    (with_discids, Include::Subquery(Subquery::DiscIds)),
     (
         with_release_groups,
         Include::Subquery(Subquery::ReleaseGroups)
@@ -366,3 +1337,905 @@ impl_includes!(
         Include::Subquery(Subquery::ArtistCredits)
     )
 );
+
+impl FetchQuery<Release> {
+    /// Include everything a full release page needs: artist credits, labels, recordings, release
+    /// group and genres.
+    pub fn with_full_details(&mut self) -> &mut Self {
+        self.with_artist_credits()
+            .with_labels()
+            .with_recordings()
+            .with_release_groups()
+            .with_genres()
+    }
+
+    /// Include the recordings along with their artist credits, as `inc=recordings+artist-credits`.
+    /// MusicBrainz applies the `artist-credits` include to the tracks and recordings too, filling
+    /// [`Track::artist_credit`] and the [`Recording::artist_credit`] of every track.
+    pub fn with_recording_credits(&mut self) -> &mut Self {
+        self.with_recordings().with_artist_credits()
+    }
+
+    /// Include the minimum to display a release in a list: artist credits and release group.
+    pub fn with_display_basics(&mut self) -> &mut Self {
+        self.with_artist_credits().with_release_groups()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::search::SearchResult;
+    use crate::entity::BrowseResult;
+    use crate::Browse;
+
+    #[test]
+    fn should_count_media_discids() {
+        let input = r#"
+            {
+                "title": "",
+                "position": 1,
+                "track-count": 12,
+                "format-id": "9712d52a-4509-3d4b-a1a2-67c88c643e31",
+                "format": "CD",
+                "discs": [
+                    {
+                        "id": "bXQKRgpsMn4uxxKqHXwzVMYRC5o-",
+                        "offset-count": 12,
+                        "sectors": 186508,
+                        "offsets": [150, 15953, 31439, 46290, 59255, 74602, 91444, 105384, 122373, 136860, 153498, 168438]
+                    },
+                    {
+                        "id": "xQXM6MhHePM_h8wPHtUBFlOKXLQ-",
+                        "offset-count": 12,
+                        "sectors": 186263,
+                        "offsets": [182, 15985, 31471, 46322, 59287, 74634, 91476, 105416, 122405, 136892, 153530, 168470]
+                    }
+                ]
+            }"#;
+
+        let media: Media = serde_json::from_str(input).unwrap();
+
+        assert!(media.has_discids());
+        assert_eq!(media.disc_count_actual(), 2);
+    }
+
+    #[test]
+    fn should_report_missing_discids() {
+        let input = r#"
+            {
+                "position": 1,
+                "track-count": 12,
+                "format": "CD"
+            }"#;
+
+        let media: Media = serde_json::from_str(input).unwrap();
+
+        assert!(!media.has_discids());
+        assert_eq!(media.disc_count_actual(), 0);
+    }
+
+    #[test]
+    fn should_detect_truncated_tracklist() {
+        let tracks: Vec<serde_json::Value> = (1..=500)
+            .map(|position| {
+                serde_json::json!({
+                    "id": format!("track-{}", position),
+                    "title": format!("Track {}", position),
+                    "number": position.to_string(),
+                    "position": position,
+                    "length": 180000,
+                    "recording": {"id": format!("recording-{}", position), "title": format!("Track {}", position)}
+                })
+            })
+            .collect();
+        let input = serde_json::json!({
+            "position": 1,
+            "format": "Digital Media",
+            "track-count": 642,
+            "track-offset": 0,
+            "tracks": tracks
+        });
+
+        let mut media: Media = serde_json::from_value(input).unwrap();
+
+        assert!(media.is_tracklist_truncated());
+
+        media.track_count = 500;
+        assert!(!media.is_tracklist_truncated());
+
+        media.tracks = None;
+        assert!(!media.is_tracklist_truncated());
+    }
+
+    #[test]
+    fn should_detect_truncated_release_tracklist() {
+        let input = r#"{
+            "id": "a9a4d5a4-3c9b-4f1e-8a0e-3f1d0c5d7e21",
+            "title": "Anjunadeep 10",
+            "media": [
+                {"position": 1, "track-count": 1, "track-offset": 0, "tracks": [
+                    {"id": "t1", "title": "Intro", "number": "1", "position": 1, "length": null,
+                     "recording": {"id": "r1", "title": "Intro"}}
+                ]},
+                {"position": 2, "track-count": 3, "track-offset": 0, "tracks": [
+                    {"id": "t2", "title": "Outro", "number": "1", "position": 1, "length": null,
+                     "recording": {"id": "r2", "title": "Outro"}}
+                ]}
+            ]
+        }"#;
+
+        let release: Release = serde_json::from_str(input).unwrap();
+        let media = release.media.as_ref().unwrap();
+
+        assert!(release.has_truncated_tracklist());
+        assert!(!media[0].is_tracklist_truncated());
+        assert!(media[1].is_tracklist_truncated());
+    }
+
+    #[test]
+    fn should_deserialize_release_search_fields() {
+        let input = r#"{
+            "created": "2024-05-01T12:00:00.123Z",
+            "count": 1,
+            "offset": 0,
+            "releases": [{
+                "id": "18d4e9b4-9247-4b44-914a-8ddec3502103",
+                "score": 100,
+                "status-id": "4e304316-386d-3409-af2e-78857eec5cfe",
+                "packaging-id": "ec27701a-4a22-37f4-bfac-6616e0f9750a",
+                "count": 1,
+                "title": "Drum Kit Pieces",
+                "status": "Official",
+                "packaging": "Jewel Case",
+                "text-representation": {"language": "eng", "script": "Latn"},
+                "date": "2012-11-23",
+                "country": "GB",
+                "barcode": "5060130270089",
+                "track-count": 9
+            }]
+        }"#;
+
+        let result: SearchResult<Release> = serde_json::from_str(input).unwrap();
+        let release = &result.entities[0];
+
+        assert_eq!(release.status, Some(ReleaseStatus::Official));
+        assert_eq!(release.packaging, Some(ReleasePackaging::JewelCase));
+        assert_eq!(release.country.as_deref(), Some("GB"));
+        // Search results don't include the data quality
+        assert_eq!(release.quality, None);
+    }
+
+    #[test]
+    fn should_include_full_details_preset() {
+        let mut query = Release::fetch();
+        query
+            .id("18d4e9b4-9247-4b44-914a-8ddec3502103")
+            .with_full_details();
+        query.params_to_path();
+
+        assert!(query
+            .0
+            .path
+            .ends_with("&inc=artist-credits+labels+recordings+release-groups+genres"));
+
+        let mut query = Release::fetch();
+        query.with_display_basics();
+        query.params_to_path();
+
+        assert!(query.0.path.ends_with("&inc=artist-credits+release-groups"));
+    }
+
+    #[test]
+    fn should_list_disc_titles() {
+        let input = r#"{
+            "id": "0f3d2b0f-1c2e-4d8f-9e6e-2f5a1d3c4b5a",
+            "title": "Greatest Hits",
+            "media": [
+                {"position": 2, "title": "", "track-count": 10, "format": "CD"},
+                {"position": null, "title": "The Videos", "track-count": 6, "format": "DVD"},
+                {"position": 1, "title": "The Hits", "track-count": 12, "format": "CD"},
+                {"position": 3, "title": "The Rarities", "track-count": 8, "format": "CD"}
+            ]
+        }"#;
+
+        let release: Release = serde_json::from_str(input).unwrap();
+
+        assert_eq!(
+            release.disc_titles(),
+            vec![
+                Some("The Hits".to_string()),
+                None,
+                Some("The Rarities".to_string()),
+                Some("The Videos".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn should_deserialize_text_representation() {
+        let input = r#"{"language": "jpn", "script": "Latn"}"#;
+        let text_representation: ReleaseTextRepresentation = serde_json::from_str(input).unwrap();
+
+        assert_eq!(text_representation.language, Some(Language::Jpn));
+        assert_eq!(text_representation.script, Some(ReleaseScript::Latn));
+
+        let input = r#"{"language": null, "script": null}"#;
+        let text_representation: ReleaseTextRepresentation = serde_json::from_str(input).unwrap();
+
+        assert_eq!(text_representation.language, None);
+        assert_eq!(text_representation.script, None);
+    }
+
+    #[test]
+    fn should_deserialize_unknown_language() {
+        let language: Language = serde_json::from_str(r#""tlh""#).unwrap();
+
+        assert_eq!(language, Language::UnrecognizedLanguage);
+    }
+
+    #[test]
+    fn should_display_language_name() {
+        assert_eq!(Language::Eng.to_string(), "English");
+        assert_eq!(Language::Jpn.to_string(), "Japanese");
+        assert_eq!(Language::Deu.to_string(), "German");
+        assert_eq!(Language::Fra.to_string(), "French");
+        assert_eq!(Language::Zxx.to_string(), "No linguistic content");
+    }
+
+    #[test]
+    fn should_display_script_name() {
+        assert_eq!(ReleaseScript::Latn.to_string(), "Latin");
+        assert_eq!(ReleaseScript::Cyrl.to_string(), "Cyrillic");
+        assert_eq!(ReleaseScript::Jpan.to_string(), "Japanese");
+        assert_eq!(ReleaseScript::Hant.to_string(), "Han (Traditional variant)");
+        assert_eq!(ReleaseScript::Qaaa.to_string(), "Multiple scripts");
+    }
+
+    #[test]
+    fn should_display_release_status_packaging_and_quality() {
+        assert_eq!(ReleaseStatus::PseudoRelease.to_string(), "Pseudo-Release");
+        assert_eq!(ReleaseStatus::Official.to_string(), "Official");
+        assert_eq!(ReleasePackaging::JewelCase.to_string(), "Jewel Case");
+        assert_eq!(
+            ReleasePackaging::CardboardPaperSleeve.to_string(),
+            "Cardboard/Paper Sleeve"
+        );
+        assert_eq!(ReleasePackaging::Snappack.to_string(), "SnapPack");
+        assert_eq!(ReleaseQuality::High.to_string(), "High");
+
+        for packaging in [
+            ReleasePackaging::JewelCase,
+            ReleasePackaging::SlimJewelCase,
+            ReleasePackaging::SuperJewelBox,
+        ] {
+            let serialized = serde_json::to_value(&packaging).unwrap();
+            assert_eq!(serialized, packaging.to_string());
+        }
+    }
+
+    #[test]
+    fn should_deserialize_unknown_script() {
+        let script: ReleaseScript = serde_json::from_str(r#""Zsym""#).unwrap();
+
+        assert_eq!(script, ReleaseScript::UnrecognizedReleaseScript);
+    }
+
+    #[test]
+    fn should_normalize_format_synonyms() {
+        for (input, expected) in [
+            ("CD", MediaFormat::CD),
+            ("compact disc", MediaFormat::CD),
+            ("12\" vinyl", MediaFormat::TwelveInchVinyl),
+            ("12 inch Vinyl", MediaFormat::TwelveInchVinyl),
+            ("7in", MediaFormat::SevenInchVinyl),
+            ("LP", MediaFormat::Vinyl),
+            ("tape", MediaFormat::Cassette),
+            ("digital-media", MediaFormat::DigitalMedia),
+            ("Blu-Ray Disc", MediaFormat::BluRay),
+        ] {
+            assert_eq!(normalize_format(input), Some(expected), "{}", input);
+        }
+        assert_eq!(normalize_format("wax cylinder"), None);
+        assert_eq!(normalize_format("  "), None);
+    }
+
+    #[test]
+    fn should_search_normalized_format() {
+        let query = ReleaseSearchQuery::query_builder()
+            .format_normalized("12 inch vinyl")
+            .build();
+        let unknown = ReleaseSearchQuery::query_builder()
+            .format_normalized("wax cylinder")
+            .build();
+
+        assert_eq!(query, r#"query=format:"12\" Vinyl""#);
+        assert_eq!(unknown, r#"query=format:"wax cylinder""#);
+    }
+
+    #[test]
+    fn should_count_media_and_discs() {
+        let json = r#"{
+            "id": "b6b2bb7e-8a2d-4b8f-b5a5-b5d2a4b2a5d3",
+            "title": "Nevermind",
+            "media": [
+                {"position": 1, "format": "CD", "track-count": 13, "disc-count": 2},
+                {"position": 2, "format": "CD", "track-count": 11, "disc-count": 1}
+            ]
+        }"#;
+
+        let release: Release = serde_json::from_str(json).unwrap();
+        let without_media: Release = serde_json::from_str(
+            r#"{"id": "b6b2bb7e-8a2d-4b8f-b5a5-b5d2a4b2a5d3", "title": "Nevermind"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(release.medium_count(), 2);
+        assert_eq!(release.total_disc_count(), 3);
+        assert_eq!(without_media.medium_count(), 0);
+        assert_eq!(without_media.total_disc_count(), 0);
+    }
+
+    #[test]
+    fn should_browse_releases_by_status() {
+        let mut query = Release::browse();
+        query
+            .status(ReleaseStatus::Official)
+            .by_recording("b9ad642e-b012-41c7-b72a-42cf4911f9ff");
+
+        assert!(query.inner.path.ends_with(
+            "/release?fmt=json&status=official&recording=b9ad642e-b012-41c7-b72a-42cf4911f9ff"
+        ));
+
+        let mut query = Release::browse();
+        query
+            .by_recording("b9ad642e-b012-41c7-b72a-42cf4911f9ff")
+            .status(ReleaseStatus::PseudoRelease);
+
+        assert!(query.inner.path.ends_with("&status=pseudo-release"));
+    }
+
+    #[test]
+    fn should_deserialize_browse_with_mixed_statuses() {
+        let input = r#"{
+            "release-count": 4,
+            "release-offset": 0,
+            "releases": [
+                {"id": "r1", "title": "Nevermind", "status": "Official", "packaging": "Jewel Case"},
+                {"id": "r2", "title": "Nevermind", "status": "Promotion", "packaging": "Cardboard/Paper Sleeve"},
+                {"id": "r3", "title": "Nevermind", "status": "Bootleg", "packaging": null},
+                {"id": "r4", "title": "Nevermind", "status": "Pseudo-Release", "packaging": "None"}
+            ]
+        }"#;
+
+        let result: BrowseResult<Release> = serde_json::from_str(input).unwrap();
+        let statuses: Vec<_> = result
+            .entities
+            .iter()
+            .map(|release| release.status.clone())
+            .collect();
+
+        assert_eq!(
+            statuses,
+            vec![
+                Some(ReleaseStatus::Official),
+                Some(ReleaseStatus::Promotion),
+                Some(ReleaseStatus::Bootleg),
+                Some(ReleaseStatus::PseudoRelease),
+            ]
+        );
+        assert_eq!(
+            result.entities[1].packaging,
+            Some(ReleasePackaging::CardboardPaperSleeve)
+        );
+    }
+
+    #[test]
+    fn should_fall_back_to_recording_length() {
+        let input = r#"[
+            {"id": "t1", "title": "Intro", "number": "1", "position": 1, "length": null,
+             "recording": {"id": "r1", "title": "Intro", "length": 183000}},
+            {"id": "t2", "title": "Outro", "number": "2", "position": 2, "length": 120000,
+             "recording": {"id": "r2", "title": "Outro", "length": 119000}},
+            {"id": "t3", "title": "Hidden", "number": "3", "position": 3, "length": null,
+             "recording": {"id": "r3", "title": "Hidden", "length": null}}
+        ]"#;
+
+        let tracks: Vec<Track> = serde_json::from_str(input).unwrap();
+
+        assert_eq!(tracks[0].effective_length(), Some(183000));
+        assert_eq!(tracks[1].effective_length(), Some(120000));
+        assert_eq!(tracks[2].effective_length(), None);
+    }
+
+    #[test]
+    fn should_page_label_releases_by_type() {
+        let mut query = Release::browse();
+        query
+            .by_label("47e718e1-7ee4-460c-b1cc-1192a841c6e5")
+            .release_type(ReleaseGroupPrimaryType::Ep)
+            .limit(25)
+            .offset(50);
+        query.include_to_path();
+
+        assert!(query.inner.path.ends_with(
+            "/release?fmt=json&label=47e718e1-7ee4-460c-b1cc-1192a841c6e5&type=ep&limit=25&offset=50"
+        ));
+
+        let input = r#"{
+            "release-count": 112,
+            "release-offset": 50,
+            "releases": [
+                {"id": "r1", "title": "Chip Fight EP", "status": "Official"},
+                {"id": "r2", "title": "8-bit Shred EP", "status": "Official"}
+            ]
+        }"#;
+        let page: BrowseResult<Release> = serde_json::from_str(input).unwrap();
+
+        assert_eq!(page.total_pages(25), 5);
+        assert_eq!(page.current_page(25), 3);
+        assert_eq!(page.entities.len(), 2);
+    }
+
+    fn recording_releases(count: usize, offset: usize, ids: &[&str]) -> String {
+        let releases: Vec<_> = ids
+            .iter()
+            .map(|id| serde_json::json!({"id": id, "title": "Nevermind"}))
+            .collect();
+
+        serde_json::json!({
+            "release-count": count,
+            "release-offset": offset,
+            "releases": releases,
+        })
+        .to_string()
+    }
+
+    fn recording_page(recording: &str, offset: u16) -> mockito::Matcher {
+        mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("recording".to_string(), recording.to_string()),
+            mockito::Matcher::UrlEncoded("offset".to_string(), offset.to_string()),
+        ])
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn should_merge_releases_of_several_recordings() {
+        let mut server = mockito::Server::new();
+        let smells = server
+            .mock("GET", "/release")
+            .match_query(recording_page("rec-1", 0))
+            .with_body(recording_releases(3, 0, &["r1", "r2"]))
+            .create();
+        let smells_next_page = server
+            .mock("GET", "/release")
+            .match_query(recording_page("rec-1", 2))
+            .with_body(recording_releases(3, 2, &["r3"]))
+            .create();
+        let lithium = server
+            .mock("GET", "/release")
+            .match_query(recording_page("rec-2", 0))
+            .with_body(recording_releases(2, 0, &["r2", "r4"]))
+            .create();
+
+        let releases = crate::browse_query_at::<Release>(&server.url())
+            .by_recordings(&["rec-1", "rec-2"])
+            .execute()
+            .unwrap();
+
+        smells.assert();
+        smells_next_page.assert();
+        lithium.assert();
+        let ids: Vec<_> = releases.iter().map(|release| release.id.as_str()).collect();
+        assert_eq!(ids, vec!["r1", "r2", "r3", "r4"]);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_merge_releases_of_several_recordings() {
+        let mut server = mockito::Server::new_async().await;
+        let smells = server
+            .mock("GET", "/release")
+            .match_query(recording_page("rec-1", 0))
+            .with_body(recording_releases(3, 0, &["r1", "r2"]))
+            .create_async()
+            .await;
+        let smells_next_page = server
+            .mock("GET", "/release")
+            .match_query(recording_page("rec-1", 2))
+            .with_body(recording_releases(3, 2, &["r3"]))
+            .create_async()
+            .await;
+        let lithium = server
+            .mock("GET", "/release")
+            .match_query(recording_page("rec-2", 0))
+            .with_body(recording_releases(2, 0, &["r2", "r4"]))
+            .create_async()
+            .await;
+
+        let releases = crate::browse_query_at::<Release>(&server.url())
+            .by_recordings(&["rec-1", "rec-2"])
+            .execute()
+            .await
+            .unwrap();
+
+        smells.assert_async().await;
+        smells_next_page.assert_async().await;
+        lithium.assert_async().await;
+        let ids: Vec<_> = releases.iter().map(|release| release.id.as_str()).collect();
+        assert_eq!(ids, vec!["r1", "r2", "r3", "r4"]);
+    }
+
+    #[test]
+    fn should_deserialize_media_format() {
+        let input = r#"[
+            {"position": 1, "track-count": 12, "title": "",
+             "format-id": "3e9080b0-5e6c-34ab-bd15-f526b6306a64", "format": "12\" Vinyl"},
+            {"position": 2, "track-count": 3, "title": "",
+             "format-id": "0a1b2c3d-0000-4000-8000-000000000000", "format": "Hologram Crystal"},
+            {"position": 3, "track-count": 1, "title": "", "format-id": null, "format": null}
+        ]"#;
+
+        let media: Vec<Media> = serde_json::from_str(input).unwrap();
+
+        assert_eq!(media[0].format, Some(MediaFormat::TwelveInchVinyl));
+        assert_eq!(
+            media[0].format_id.as_deref(),
+            Some("3e9080b0-5e6c-34ab-bd15-f526b6306a64")
+        );
+        assert_eq!(
+            media[1].format,
+            Some(MediaFormat::UnrecognizedMediaFormat(
+                "Hologram Crystal".to_string()
+            ))
+        );
+        assert_eq!(
+            media[1].format_id.as_deref(),
+            Some("0a1b2c3d-0000-4000-8000-000000000000")
+        );
+        assert_eq!(media[2].format, None);
+
+        for format in [
+            MediaFormat::TwelveInchVinyl,
+            MediaFormat::UnrecognizedMediaFormat("Hologram Crystal".to_string()),
+        ] {
+            let value = serde_json::to_value(&format).unwrap();
+            assert_eq!(
+                serde_json::from_value::<MediaFormat>(value).unwrap(),
+                format
+            );
+        }
+        assert_eq!(
+            serde_json::to_value(MediaFormat::TwelveInchVinyl).unwrap(),
+            "12\" Vinyl"
+        );
+        assert_eq!(
+            serde_json::to_value(&media[1].format).unwrap(),
+            "Hologram Crystal"
+        );
+    }
+
+    #[test]
+    fn should_deserialize_release_quality_consistently() {
+        let quality = |field: &str| {
+            let release: Release =
+                serde_json::from_str(&format!(r#"{{"id": "r1", "title": "Bleach"{}}}"#, field))
+                    .unwrap();
+            release.quality
+        };
+
+        assert_eq!(quality(""), None);
+        assert_eq!(quality(r#", "quality": null"#), None);
+        assert_eq!(
+            quality(r#", "quality": "unknown""#),
+            Some(ReleaseQuality::Unknown)
+        );
+        assert_eq!(
+            quality(r#", "quality": "high""#),
+            Some(ReleaseQuality::High)
+        );
+        assert_eq!(
+            quality(r#", "quality": "pristine""#),
+            Some(ReleaseQuality::Unknown)
+        );
+    }
+
+    #[test]
+    fn should_order_media_by_position() {
+        let input = r#"{
+            "id": "r1",
+            "title": "With the Lights Out",
+            "media": [
+                {"position": 3, "track-count": 20, "title": "", "format": "CD"},
+                {"position": null, "track-count": 1, "title": "Bonus", "format": "DVD-Video"},
+                {"position": 1, "track-count": 21, "title": "", "format": "CD"},
+                {"position": 2, "track-count": 20, "title": "", "format": "CD"}
+            ]
+        }"#;
+
+        let release: Release = serde_json::from_str(input).unwrap();
+        let positions: Vec<_> = release
+            .media_ordered()
+            .iter()
+            .map(|media| media.position)
+            .collect();
+
+        assert_eq!(positions, vec![Some(1), Some(2), Some(3), None]);
+    }
+
+    #[test]
+    fn should_deserialize_pregap_and_data_tracks() {
+        let track = |id: &str, position: u32, title: &str, video: bool| {
+            serde_json::json!({
+                "id": id,
+                "number": position.to_string(),
+                "position": position,
+                "title": title,
+                "length": 180000,
+                "recording": {"id": id, "title": title, "video": video}
+            })
+        };
+        let input = serde_json::json!({
+            "position": 1,
+            "track-count": 2,
+            "title": "",
+            "format": "Enhanced CD",
+            "pregap": track("30e5b6fb-3f6c-4b1d-8b0e-b0c9d6bbb0a0", 0, "Hidden", false),
+            "tracks": [track("e3d2b2f2-4d51-4e36-9f1b-4bd30ed5cf72", 1, "Lithium", false)],
+            "data-tracks": [track("9a61ad7c-6e36-4b61-8f77-ff7c49b4bb6b", 2, "Lithium (video)", true)]
+        });
+
+        let media: Media = serde_json::from_value(input).unwrap();
+
+        assert_eq!(media.pregap.as_ref().unwrap().position, 0);
+        assert_eq!(media.tracks.as_ref().unwrap().len(), 1);
+        let data_tracks = media.data_tracks.unwrap();
+        assert_eq!(data_tracks[0].title, "Lithium (video)");
+        assert!(data_tracks[0].is_video());
+
+        let media: Media =
+            serde_json::from_str(r#"{"position": 1, "track-count": 0, "title": ""}"#).unwrap();
+        assert_eq!(media.pregap, None);
+        assert_eq!(media.data_tracks, None);
+    }
+
+    #[test]
+    fn should_find_earliest_release_event() {
+        let input = r#"{
+            "id": "1b022e01-4da6-387b-8658-8678046e4cef",
+            "title": "Nevermind",
+            "date": "1991-09-24",
+            "country": "US",
+            "release-events": [
+                {"date": "1991-09-24", "area": {
+                    "id": "489ce91b-6658-3307-9877-795b68554c98", "name": "United States",
+                    "sort-name": "United States", "iso-3166-1-codes": ["US"]}},
+                {"date": "1991-09-23", "area": {
+                    "id": "8a754a16-0027-3a29-b6d7-2b40ea0481ed", "name": "United Kingdom",
+                    "sort-name": "United Kingdom", "iso-3166-1-codes": ["GB"]}},
+                {"date": "", "area": null}
+            ]
+        }"#;
+
+        let release: Release = serde_json::from_str(input).unwrap();
+        let events = release.release_events.as_ref().unwrap();
+
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[1].area.as_ref().unwrap().name, "United Kingdom");
+        assert_eq!(events[2].date, None);
+        assert_eq!(
+            release.earliest_release_date(),
+            NaiveDate::from_ymd_opt(1991, 9, 23)
+        );
+
+        let undated: Release = serde_json::from_str(
+            r#"{"id": "1b022e01-4da6-387b-8658-8678046e4cef", "title": "Nevermind",
+                "date": "1991-09-24"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            undated.earliest_release_date(),
+            NaiveDate::from_ymd_opt(1991, 9, 24)
+        );
+    }
+
+    #[test]
+    fn should_resolve_release_event_country_codes() {
+        let input = r#"{
+            "id": "b52a8f31-b5ab-34e9-92f4-f5b7110220f0",
+            "title": "Nevermind",
+            "release-events": [
+                {"date": "1991-09-24", "area": {
+                    "id": "489ce91b-6658-3307-9877-795b68554c98", "name": "United States",
+                    "sort-name": "United States", "iso-3166-1-codes": ["US"]}},
+                {"date": "1991-09-30", "area": {
+                    "id": "85752fda-13c4-31a3-bee5-0e5cb1f51dad", "name": "Germany",
+                    "sort-name": "Germany", "iso-3166-1-codes": ["DE"]}},
+                {"date": "1991-10-01", "area": {
+                    "id": "f03d09b3-39dc-4083-afd6-159e3f0d462f", "name": "London",
+                    "sort-name": "London"}},
+                {"date": "1992-01-10", "area": {
+                    "id": "489ce91b-6658-3307-9877-795b68554c98", "name": "United States",
+                    "sort-name": "United States", "iso-3166-1-codes": ["US"]}},
+                {"date": "2011-09-26", "area": {
+                    "id": "525d4e18-3d00-31b9-a58b-a146a916de8f", "name": "[Worldwide]",
+                    "sort-name": "[Worldwide]", "iso-3166-1-codes": ["XW"]}},
+                {"date": "1992", "area": null}
+            ]
+        }"#;
+
+        let release: Release = serde_json::from_str(input).unwrap();
+        let events = release.release_events.as_ref().unwrap();
+
+        assert_eq!(events[1].country_code(), Some("DE"));
+        assert_eq!(events[2].country_code(), None);
+        assert_eq!(events[5].country_code(), None);
+        assert_eq!(release.release_country_codes(), ["US", "DE", "XW"]);
+    }
+
+    #[test]
+    fn should_detect_digital_release() {
+        let release = |formats: &[&str]| -> Release {
+            let media: Vec<String> = formats
+                .iter()
+                .map(|format| format!(r#"{{"track-count": 1, "format": {}}}"#, format))
+                .collect();
+            serde_json::from_str(&format!(
+                r#"{{"id": "1b022e01-4da6-387b-8658-8678046e4cef", "title": "Nevermind",
+                    "media": [{}]}}"#,
+                media.join(", ")
+            ))
+            .unwrap()
+        };
+
+        assert!(release(&[r#""Digital Media""#, r#""Digital Media""#]).is_digital());
+        assert!(!release(&[r#""Digital Media""#, r#""CD""#]).is_digital());
+        assert!(!release(&[r#""Digital Media""#, "null"]).is_digital());
+        assert!(!release(&[]).is_digital());
+    }
+
+    #[test]
+    fn should_read_title_language_and_script() {
+        let release: Release = serde_json::from_str(
+            r#"{"id": "b2a3a9b5-7f1c-4c0e-8b5a-2f5b8c3a9d10", "title": "千のナイフ",
+                "text-representation": {"language": "jpn", "script": "Jpan"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(release.title_language(), Some(Language::Jpn));
+        assert_eq!(release.title_script(), Some(ReleaseScript::Jpan));
+
+        let release: Release = serde_json::from_str(
+            r#"{"id": "b2a3a9b5-7f1c-4c0e-8b5a-2f5b8c3a9d10", "title": "千のナイフ"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(release.title_language(), None);
+        assert_eq!(release.title_script(), None);
+    }
+
+    #[test]
+    fn should_flag_pregap_in_flat_tracklist() {
+        let track = |id: &str, position: u32, title: &str| {
+            serde_json::json!({
+                "id": id,
+                "number": position.to_string(),
+                "position": position,
+                "title": title,
+                "recording": {"id": id, "title": title}
+            })
+        };
+        let release: Release = serde_json::from_value(serde_json::json!({
+            "id": "1b022e01-4da6-387b-8658-8678046e4cef",
+            "title": "Nevermind",
+            "media": [
+                {"position": 2, "track-count": 1, "format": "CD",
+                 "tracks": [track("f0a6e5a4-3c3b-4f6e-9f8e-4a1b2c3d4e5f", 1, "Sliver")]},
+                {"position": 1, "track-count": 1, "format": "Enhanced CD",
+                 "pregap": track("30e5b6fb-3f6c-4b1d-8b0e-b0c9d6bbb0a0", 0, "Hidden"),
+                 "tracks": [track("e3d2b2f2-4d51-4e36-9f1b-4bd30ed5cf72", 1, "Lithium")],
+                 "data-tracks": [track("9a61ad7c-6e36-4b61-8f77-ff7c49b4bb6b", 2, "Video")]}
+            ]
+        }))
+        .unwrap();
+
+        let tracklist = release.flat_tracklist();
+        let summary: Vec<_> = tracklist
+            .iter()
+            .map(|flat| (flat.media.position, flat.track.title.as_str(), flat.kind))
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                (Some(1), "Hidden", TrackKind::Pregap),
+                (Some(1), "Lithium", TrackKind::Audio),
+                (Some(1), "Video", TrackKind::Data),
+                (Some(2), "Sliver", TrackKind::Audio),
+            ]
+        );
+        assert!(tracklist[0].is_pregap());
+        assert_eq!(tracklist[0].track.position, 0);
+        assert_eq!(tracklist[0].media.format, Some(MediaFormat::EnhancedCD));
+        assert!(!tracklist[1].is_pregap());
+    }
+
+    #[test]
+    fn should_include_recording_credits() {
+        let mut query = Release::fetch();
+        query
+            .id("18d4e9b4-9247-4b44-914a-8ddec3502103")
+            .with_recording_credits();
+        query.params_to_path();
+
+        assert!(query.0.path.ends_with(
+            "/release/18d4e9b4-9247-4b44-914a-8ddec3502103?fmt=json&inc=recordings+artist-credits"
+        ));
+    }
+
+    #[test]
+    fn should_deserialize_nested_track_credits() {
+        let release: Release = serde_json::from_str(
+            r#"{
+                "id": "3f1b2c4d-5e6f-4a7b-8c9d-0e1f2a3b4c5d",
+                "title": "Now That's What I Call Music! 1",
+                "artist-credit": [
+                    {"name": "Various Artists", "joinphrase": "",
+                     "artist": {"id": "89ad4ac3-39f7-470e-963a-56509c546377", "name": "Various Artists", "sort-name": "Various Artists"}}
+                ],
+                "media": [{
+                    "position": 1,
+                    "track-count": 1,
+                    "tracks": [{
+                        "id": "8f3b1e6a-0c2d-4b5e-9a7f-1d2c3b4a5e6f",
+                        "title": "You Can't Hurry Love",
+                        "number": "1",
+                        "position": 1,
+                        "length": 174000,
+                        "artist-credit": [
+                            {"name": "Phil Collins", "joinphrase": "",
+                             "artist": {"id": "7e9bd05a-117f-4cce-87bc-e011527a8b18", "name": "Phil Collins", "sort-name": "Collins, Phil"}}
+                        ],
+                        "recording": {
+                            "id": "2c1d0e9f-8a7b-4c6d-5e4f-3a2b1c0d9e8f",
+                            "title": "You Can't Hurry Love",
+                            "artist-credit": [
+                                {"name": "Phil Collins", "joinphrase": "",
+                                 "artist": {"id": "7e9bd05a-117f-4cce-87bc-e011527a8b18", "name": "Phil Collins", "sort-name": "Collins, Phil"}}
+                            ]
+                        }
+                    }]
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        let track = &release.media.as_ref().unwrap()[0].tracks.as_ref().unwrap()[0];
+
+        assert_eq!(release.artist_credit.unwrap()[0].name, "Various Artists");
+        assert_eq!(
+            track.artist_credit.as_ref().unwrap()[0].name,
+            "Phil Collins"
+        );
+        assert_eq!(
+            track.recording.artist_credit.as_ref().unwrap()[0].artist.id,
+            "7e9bd05a-117f-4cce-87bc-e011527a8b18"
+        );
+    }
+
+    #[test]
+    fn should_request_all_relations() {
+        use crate::Fetch;
+
+        let mut query = Release::fetch();
+        query
+            .id("18d4e9b4-9247-4b44-914a-8ddec3502103")
+            .with_all_relations();
+
+        assert!(query.url().ends_with(
+            "?fmt=json&inc=area-rels+artist-rels+event-rels+instrument-rels+label-rels\
+             +place-rels+recording-rels+release-rels+release-group-rels+series-rels+url-rels\
+             +work-rels+work-level-rels+recording-level-rels"
+        ));
+    }
+}
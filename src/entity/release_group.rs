@@ -3,9 +3,10 @@ use crate::date_format;
 use crate::entity::alias::Alias;
 use crate::entity::artist_credit::ArtistCredit;
 use crate::entity::genre::Genre;
+use crate::entity::mbid::Mbid;
 use crate::entity::rating::Rating;
 use crate::entity::relations::Relation;
-use crate::entity::release::Release;
+use crate::entity::release::{Release, ReleaseStatus};
 use crate::entity::tag::Tag;
 use crate::entity::BrowseBy;
 use chrono::NaiveDate;
@@ -14,57 +15,106 @@ use serde::{Deserialize, Serialize};
 
 /// A release group, just as the name suggests, is used to group several different releases into a
 /// single logical entity. Every release belongs to one, and only one release group.
-#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Clone)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 #[serde(rename_all(deserialize = "kebab-case"))]
-#[serde(default)]
 pub struct ReleaseGroup {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
 
+    /// The stable id of the [`primary_type`](ReleaseGroup::primary_type), as found in the
+    /// `release_group_primary_type` table of the MusicBrainz database.
+    #[serde(default)]
     pub primary_type_id: Option<String>,
 
     /// The type describes what kind of releases the release group represents, for example album,
     /// single, soundtrack, compilation etc.
     /// See the Type subpage for a full list of release group types.
+    #[serde(default)]
     pub primary_type: Option<ReleaseGroupPrimaryType>,
 
+    /// The stable ids of the [`secondary_types`](ReleaseGroup::secondary_types), in the same order.
+    #[serde(default)]
     pub secondary_type_ids: Vec<String>,
+    #[serde(default)]
     pub secondary_types: Vec<ReleaseGroupSecondaryType>,
 
     #[serde(deserialize_with = "date_format::deserialize_opt")]
+    #[serde(default)]
     pub first_release_date: Option<NaiveDate>,
 
     /// The title of a release group is usually very similar, if not the same, as the titles of the
     /// releases contained within it.
+    #[serde(default)]
     pub title: String,
     /// The disambiguation comments are fields in the database used to help distinguish identically
     /// named artists, labels and other entities.
+    #[serde(default)]
     pub disambiguation: String,
     /// Relationships are a way to represent all the different ways in which entities are connected
     /// to each other and to URLs outside MusicBrainz.
+    #[serde(default)]
     pub relations: Option<Vec<Relation>>,
     /// Artist credits indicate who is the main credited artist (or artists) for releases, release
     /// groups, tracks and recordings, and how they are credited..
+    #[serde(default)]
     pub artist_credit: Option<Vec<ArtistCredit>>,
     /// Releases present in this release group.
+    #[serde(default)]
     pub releases: Option<Vec<Release>>,
+    #[serde(default)]
     pub tags: Option<Vec<Tag>>,
+    #[serde(default)]
     pub rating: Option<Rating>,
+    /// The rating the authenticated user gave, loaded with the `user-ratings` include.
+    #[serde(default)]
+    pub user_rating: Option<Rating>,
     /// Aliases are alternate names for a release group.
+    #[serde(default)]
     pub aliases: Option<Vec<Alias>>,
     /// Genres are currently supported in MusicBrainz as part of the tag system.
+    #[serde(default)]
     pub genres: Option<Vec<Genre>>,
     /// Annotations are text fields, functioning like a miniature wiki, that can be added to any
     /// existing artists, labels, recordings, releases, release groups and works.
+    #[serde(default)]
     pub annotation: Option<String>,
 }
 
+impl ReleaseGroup {
+    /// The loaded releases, oldest first. Releases without a date come last.
+    pub fn releases_by_date(&self) -> Vec<&Release> {
+        let mut releases: Vec<&Release> = self.releases.iter().flatten().collect();
+        releases.sort_by_key(|release| (release.date.is_none(), release.date));
+        releases
+    }
+
+    /// The loaded releases with the given status.
+    pub fn releases_with_status(&self, status: &ReleaseStatus) -> Vec<&Release> {
+        self.releases
+            .iter()
+            .flatten()
+            .filter(|release| release.status.as_ref() == Some(status))
+            .collect()
+    }
+
+    /// The loaded releases with at least one medium of the given format, see
+    /// [`Release::has_format`]. Media formats are only loaded with `with_media`.
+    pub fn releases_with_format(&self, format: &str) -> Vec<&Release> {
+        self.releases
+            .iter()
+            .flatten()
+            .filter(|release| release.has_format(format))
+            .collect()
+    }
+}
+
 /// The primary type of a MusicBrainz release group.
 /// Note that this enum is `non_exhaustive`; The list of release types is subject to change and
 /// these changes are only reflected in the DB, not in actual MB code.
 /// Variants are derived from the `release_group_primary_type` table in the MusicBrainz database.
 #[non_exhaustive]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "tracing", serde(remote = "Self"))]
 pub enum ReleaseGroupPrimaryType {
     Album,
     Single,
@@ -78,12 +128,29 @@ pub enum ReleaseGroupPrimaryType {
     UnrecognizedReleaseGroupPrimaryType,
 }
 
+impl ReleaseGroupPrimaryType {
+    /// The name MusicBrainz gives to the type, e.g. `Album` or `EP`.
+    /// `UnrecognizedReleaseGroupPrimaryType` maps to an empty string since the original value is
+    /// not kept.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReleaseGroupPrimaryType::Album => "Album",
+            ReleaseGroupPrimaryType::Single => "Single",
+            ReleaseGroupPrimaryType::Ep => "EP",
+            ReleaseGroupPrimaryType::Broadcast => "Broadcast",
+            ReleaseGroupPrimaryType::Other => "Other",
+            ReleaseGroupPrimaryType::UnrecognizedReleaseGroupPrimaryType => "",
+        }
+    }
+}
+
 /// The secondary type of a MusicBrainz release group entity.
 /// Note that this enum is `non_exhaustive`; The list of release types is subject to change and
 /// these changes are only reflected in the DB, not in actual MB code.
 /// Variants are derived from the `release_group_secondary_type` table in the MusicBrainz database.
 #[non_exhaustive]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "tracing", serde(remote = "Self"))]
 pub enum ReleaseGroupSecondaryType {
     #[serde(rename = "Audio drama")]
     AudioDrama,
@@ -153,6 +220,8 @@ pub struct ReleaseGroupSearchQuery {
     pub release_type: String,
 }
 
+impl_try_build!(ReleaseGroupSearchQueryLuceneQueryBuilder);
+
 impl_browse! {
 ReleaseGroup,
    (by_artist, BrowseBy::Artist),
@@ -162,6 +231,14 @@ ReleaseGroup,
 
 impl_includes!(
     ReleaseGroup,
+    (
+        with_artist_relations,
+        Include::Relationship(Relationship::Artist)
+    ),
+    (
+        with_label_relations,
+        Include::Relationship(Relationship::Label)
+    ),
     (
         with_release_group_relations,
         Include::Relationship(Relationship::ReleaseGroup)
@@ -172,10 +249,242 @@ impl_includes!(
     ),
     (with_url_relations, Include::Relationship(Relationship::Url)),
     (with_artists, Include::Subquery(Subquery::Artists)),
+    (
+        with_artist_credits,
+        Include::Subquery(Subquery::ArtistCredits)
+    ),
     (with_releases, Include::Subquery(Subquery::Releases)),
+    (with_media, Include::Subquery(Subquery::Media)),
     (with_tags, Include::Subquery(Subquery::Tags)),
     (with_aliases, Include::Subquery(Subquery::Aliases)),
     (with_genres, Include::Subquery(Subquery::Genres)),
     (with_ratings, Include::Subquery(Subquery::Rating)),
+    (with_user_ratings, Include::Subquery(Subquery::UserRatings)),
     (with_annotations, Include::Subquery(Subquery::Annotations))
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::relations::RelationContent;
+    use crate::entity::release::ReleasePackaging;
+    use crate::entity::BrowseResult;
+
+    #[test]
+    fn should_deserialize_release_group_genres() {
+        let json = r#"{
+            "id": "954c5cdb-fcc7-3e0b-9d5e-a3a4e0a66b4d",
+            "title": "Nevermind",
+            "primary-type": "Album",
+            "first-release-date": "1991-09-24",
+            "genres": [
+                {"id": "cc38aba3-ba6d-4e5b-a7c1-f4d2f4e5ec23", "name": "grunge", "count": 17, "disambiguation": ""},
+                {"id": "911c7bbb-172d-4df8-9478-dbff4296e791", "name": "alternative rock", "count": 6, "disambiguation": ""}
+            ],
+            "tags": [{"name": "grunge", "count": 19}]
+        }"#;
+
+        let release_group: ReleaseGroup = serde_json::from_str(json).unwrap();
+        let genres = release_group.genres.unwrap();
+
+        assert_eq!(genres.len(), 2);
+        assert_eq!(genres[0].name, "grunge");
+        assert_eq!(genres[0].count, 17);
+        assert_eq!(release_group.tags.unwrap()[0].count, 19);
+    }
+
+    #[test]
+    fn should_deserialize_release_group_type_ids() {
+        let json = r#"{
+            "id": "a1c35a51-d102-4ce7-aca7-5b4827b2d283",
+            "title": "MTV Unplugged in New York",
+            "primary-type": "Album",
+            "primary-type-id": "f529b476-6e62-324f-b0aa-1f3e33d313fc",
+            "secondary-types": ["Live"],
+            "secondary-type-ids": ["6fd474e2-6b58-3102-9d17-d6f7eb7da0a0"]
+        }"#;
+
+        let release_group: ReleaseGroup = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            release_group.primary_type,
+            Some(ReleaseGroupPrimaryType::Album)
+        );
+        assert_eq!(
+            release_group.primary_type_id.as_deref(),
+            Some("f529b476-6e62-324f-b0aa-1f3e33d313fc")
+        );
+        assert_eq!(
+            release_group.secondary_types,
+            vec![ReleaseGroupSecondaryType::Live]
+        );
+        assert_eq!(
+            release_group.secondary_type_ids,
+            vec!["6fd474e2-6b58-3102-9d17-d6f7eb7da0a0".to_string()]
+        );
+    }
+
+    #[test]
+    fn should_deserialize_nested_release_status_and_packaging() {
+        let json = r#"{
+            "release-group-count": 1,
+            "release-group-offset": 0,
+            "release-groups": [{
+                "id": "2a0981fb-9593-3019-864b-ce934d97a16e",
+                "title": "In Utero",
+                "primary-type": "Album",
+                "first-release-date": "1993-09-21",
+                "releases": [
+                    {
+                        "id": "76df3287-6cda-33eb-8e9a-044b5e15ffdd",
+                        "title": "In Utero",
+                        "status": "Official",
+                        "status-id": "4e304316-386d-3409-af2e-78857eec5cfe",
+                        "packaging": "Jewel Case",
+                        "packaging-id": "ec27701a-4a22-37f4-bfac-6616e0f9750a",
+                        "date": "1993-09-21",
+                        "country": "US"
+                    },
+                    {
+                        "id": "6a2490ab-3f74-4ac3-8747-ffba48a3f6df",
+                        "title": "In Utero",
+                        "status": "Bootleg",
+                        "status-id": "1156806e-d06a-38bd-83f0-cf2284a808b9",
+                        "packaging": null,
+                        "packaging-id": null,
+                        "date": ""
+                    },
+                    {
+                        "id": "a1f7a8b1-3d5c-4b97-a5e1-2c0c1b9e5b2f",
+                        "title": "In Utero",
+                        "status": "Promotion"
+                    }
+                ]
+            }]
+        }"#;
+
+        let result: BrowseResult<ReleaseGroup> = serde_json::from_str(json).unwrap();
+        let releases = result.entities[0].releases.as_ref().unwrap();
+
+        assert_eq!(releases[0].status, Some(ReleaseStatus::Official));
+        assert_eq!(releases[0].packaging, Some(ReleasePackaging::JewelCase));
+        assert_eq!(releases[1].status, Some(ReleaseStatus::Bootleg));
+        assert_eq!(releases[1].packaging, None);
+        assert_eq!(releases[2].status, Some(ReleaseStatus::Promotion));
+        assert_eq!(releases[2].packaging_id, None);
+    }
+
+    #[test]
+    fn should_sort_year_only_first_release_date() {
+        let year_only: ReleaseGroup = serde_json::from_str(
+            r#"{"id": "f5093c06-23e3-404f-aeaa-40f72885ee3a", "title": "Bleach", "first-release-date": "1989"}"#,
+        )
+        .unwrap();
+        let full_date: ReleaseGroup = serde_json::from_str(
+            r#"{"id": "1b022e01-4da6-387b-8658-8678046e4cef", "title": "Nevermind", "first-release-date": "1989-06-15"}"#,
+        )
+        .unwrap();
+
+        // Missing month and day are deserialized as january 1st
+        assert_eq!(
+            year_only.first_release_date,
+            NaiveDate::from_ymd_opt(1989, 1, 1)
+        );
+
+        let mut discography = [full_date, year_only];
+        discography.sort_by_key(|release_group| release_group.first_release_date);
+
+        assert_eq!(discography[0].title, "Bleach");
+        assert_eq!(discography[1].title, "Nevermind");
+    }
+
+    #[test]
+    fn should_list_cd_pressings_oldest_first() {
+        let json = r#"{
+            "id": "1b022e01-4da6-387b-8658-8678046e4cef",
+            "title": "Nevermind",
+            "releases": [
+                {"id": "1", "title": "Nevermind", "status": "Official", "date": "2011-09-26",
+                 "media": [{"position": 1, "track-count": 13, "format": "CD"}]},
+                {"id": "2", "title": "Nevermind", "status": "Official", "date": "1991-09-24",
+                 "media": [{"position": 1, "track-count": 12, "format": "12\" Vinyl"}]},
+                {"id": "3", "title": "Nevermind", "status": "Bootleg",
+                 "media": [{"position": 1, "track-count": 12, "format": "CD"}]},
+                {"id": "4", "title": "Nevermind", "status": "Official", "date": "1991-09-24",
+                 "media": [{"position": 1, "track-count": 13, "format": "CD"}]}
+            ]
+        }"#;
+
+        let release_group: ReleaseGroup = serde_json::from_str(json).unwrap();
+
+        let ids: Vec<&str> = release_group
+            .releases_by_date()
+            .into_iter()
+            .filter(|release| release.has_format("cd"))
+            .map(|release| release.id.as_str())
+            .collect();
+        assert_eq!(ids, ["4", "1", "3"]);
+
+        assert_eq!(release_group.releases_with_format("12\" Vinyl").len(), 1);
+        assert_eq!(
+            release_group
+                .releases_with_status(&ReleaseStatus::Bootleg)
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn should_deserialize_group_level_artist_relations() {
+        let json = r#"{
+            "id": "0b7d4a31-76b6-3c3e-a5b1-3d0ad2a9f0a5",
+            "title": "Back to Mine",
+            "primary-type": "Album",
+            "secondary-types": ["Compilation", "DJ-mix"],
+            "artist-credit": [
+                {"name": "Various Artists", "joinphrase": "",
+                 "artist": {"id": "89ad4ac3-39f7-470e-963a-56509c546377", "name": "Various Artists", "sort-name": "Various Artists"}}
+            ],
+            "relations": [
+                {
+                    "type": "compiler",
+                    "type-id": "2f81887a-8674-4d8b-bd48-8bfd4c6fa332",
+                    "direction": "backward",
+                    "target-type": "artist",
+                    "attributes": [],
+                    "artist": {"id": "0c751690-c784-4a4f-b1e4-c1de27d47581", "name": "Groove Armada", "sort-name": "Groove Armada"}
+                }
+            ]
+        }"#;
+
+        let release_group: ReleaseGroup = serde_json::from_str(json).unwrap();
+        let relations = release_group.relations.unwrap();
+
+        assert_eq!(
+            release_group.artist_credit.unwrap()[0].name,
+            "Various Artists"
+        );
+        assert_eq!(relations[0].relation_type, "compiler");
+        assert!(matches!(
+            &relations[0].content,
+            RelationContent::Artist(artist) if artist.name == "Groove Armada"
+        ));
+    }
+
+    #[test]
+    fn should_request_group_level_artist_relations() {
+        use crate::Fetch;
+
+        let mut query = ReleaseGroup::fetch();
+        query
+            .id("0b7d4a31-76b6-3c3e-a5b1-3d0ad2a9f0a5")
+            .with_artist_relations()
+            .with_artist_credits();
+        query.params_to_path();
+
+        assert!(query
+            .0
+            .path
+            .ends_with("?fmt=json&inc=artist-rels+artist-credits"));
+    }
+}
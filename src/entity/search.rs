@@ -5,20 +5,42 @@ use crate::entity::cdstub::CDStub;
 use crate::entity::event::Event;
 use crate::entity::instrument::Instrument;
 use crate::entity::label::Label;
+use crate::entity::place::Place;
 use crate::entity::recording::Recording;
 use crate::entity::release::Release;
 use crate::entity::release_group::ReleaseGroup;
 use crate::entity::series::Series;
+use crate::entity::tag::Tag;
 use crate::entity::work::Work;
 use chrono::NaiveDateTime;
 use serde::Serialize;
 
+/// Search query results are wrapped in this generic struct, whatever the searched entity is.
+/// It can be named in your own function signatures to pass search results around.
+///
+/// ## Example
+/// ```rust
+/// use musicbrainz_rs_nova::entity::release::Release;
+/// use musicbrainz_rs_nova::entity::search::SearchResult;
+///
+/// fn release_titles(result: &SearchResult<Release>) -> Vec<&str> {
+///     result
+///         .entities
+///         .iter()
+///         .map(|release| release.title.as_str())
+///         .collect()
+/// }
+/// ```
 #[derive(Debug, Serialize, PartialEq, Eq, Clone)]
 #[serde(rename_all(deserialize = "kebab-case"))]
 pub struct SearchResult<T> {
+    /// When the search results were generated by the server.
     pub created: NaiveDateTime,
+    /// The total number of entities matching the query, not only the ones in this page.
     pub count: i32,
+    /// The offset of this page in the whole result list.
     pub offset: i32,
+    /// The entities in this page of results.
     pub entities: Vec<T>,
 }
 
@@ -71,6 +93,13 @@ impl Searchable for Label {
     const ENTITIES_FIELD: &'static str = "labels";
 }
 
+impl Searchable for Place {
+    const CREATED_FIELD: &'static str = "created";
+    const COUNT_FIELD: &'static str = "count";
+    const OFFSET_FIELD: &'static str = "offset";
+    const ENTITIES_FIELD: &'static str = "places";
+}
+
 impl Searchable for Recording {
     const CREATED_FIELD: &'static str = "created";
     const COUNT_FIELD: &'static str = "count";
@@ -99,6 +128,13 @@ impl Searchable for Series {
     const ENTITIES_FIELD: &'static str = "series";
 }
 
+impl Searchable for Tag {
+    const CREATED_FIELD: &'static str = "created";
+    const COUNT_FIELD: &'static str = "count";
+    const OFFSET_FIELD: &'static str = "offset";
+    const ENTITIES_FIELD: &'static str = "tags";
+}
+
 impl Searchable for Work {
     const CREATED_FIELD: &'static str = "created";
     const COUNT_FIELD: &'static str = "count";
@@ -1,6 +1,7 @@
 use super::{Include, Relationship, Subquery};
 use crate::entity::alias::Alias;
 use crate::entity::genre::Genre;
+use crate::entity::mbid::Mbid;
 use crate::entity::relations::Relation;
 use crate::entity::tag::Tag;
 use crate::entity::BrowseBy;
@@ -14,7 +15,7 @@ use lucene_query_builder::QueryBuilder;
 #[serde(rename_all(deserialize = "kebab-case"))]
 pub struct Series {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
     /// The series name is the official name of the series.
     pub name: String,
     #[serde(rename = "type")]
@@ -47,6 +48,7 @@ pub struct Series {
 /// Variants are derived from the `series_type` table in the MusicBrainz database.
 #[non_exhaustive]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "tracing", serde(remote = "Self"))]
 pub enum SeriesType {
     /// A series of release groups.
     #[serde(rename = "Release group series")]
@@ -105,6 +107,8 @@ pub struct SeriesSearchQuery {
     pub series_type: String,
 }
 
+impl_try_build!(SeriesSearchQueryLuceneQueryBuilder);
+
 impl_browse!(Series, (by_collection, BrowseBy::Collection));
 
 impl_includes!(
@@ -117,6 +121,10 @@ impl_includes!(
         with_release_group_relations,
         Include::Relationship(Relationship::ReleaseGroup)
     ),
+    (
+        with_series_relations,
+        Include::Relationship(Relationship::Series)
+    ),
     (with_tags, Include::Subquery(Subquery::Tags)),
     (with_aliases, Include::Subquery(Subquery::Aliases)),
     (with_genres, Include::Subquery(Subquery::Genres)),
@@ -1,7 +1,183 @@
+use lucene_query_builder::QueryBuilder;
 use serde::{Deserialize, Serialize};
 
+use crate::entity::area::Area;
+use crate::entity::artist::Artist;
+use crate::entity::event::Event;
+use crate::entity::genre::Genre;
+use crate::entity::instrument::Instrument;
+use crate::entity::label::Label;
+use crate::entity::place::Place;
+use crate::entity::recording::Recording;
+use crate::entity::release::Release;
+use crate::entity::release_group::ReleaseGroup;
+use crate::entity::series::Series;
+use crate::entity::work::Work;
+use crate::{Path, Search, SearchQuery};
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 pub struct Tag {
     pub name: String,
+    /// The number of users who applied the tag. Tags returned by a tag search don't carry it
+    /// and have a count of `0`.
+    #[serde(default)]
     pub count: i32,
 }
+
+/// Entities whose search accepts a `tag` field, see [`Tag::entities_with`].
+pub trait Taggable {}
+
+impl Taggable for Area {}
+impl Taggable for Artist {}
+impl Taggable for Event {}
+impl Taggable for Instrument {}
+impl Taggable for Label {}
+impl Taggable for Place {}
+impl Taggable for Recording {}
+impl Taggable for Release {}
+impl Taggable for ReleaseGroup {}
+impl Taggable for Series {}
+impl Taggable for Work {}
+
+impl Tag {
+    /// Search the entities of type `T` carrying `tag`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use musicbrainz_rs_nova::entity::release::Release;
+    /// # use musicbrainz_rs_nova::entity::tag::Tag;
+    /// let query = Tag::entities_with::<Release>("death metal");
+    /// assert!(query.url().contains("/release?fmt=json&query=tag:\"death metal\""));
+    /// ```
+    pub fn entities_with<'a, T>(tag: &str) -> SearchQuery<T>
+    where
+        T: Search<'a> + Path<'a> + Taggable,
+    {
+        let query = TagSearchQuery::query_builder().tag(tag).build();
+        T::search(query)
+    }
+}
+
+/// Tags and genres, which carry the number of users who voted for them.
+pub trait Voted {
+    /// The number of users who applied the tag or genre.
+    fn vote_count(&self) -> i64;
+}
+
+impl Voted for Tag {
+    fn vote_count(&self) -> i64 {
+        i64::from(self.count)
+    }
+}
+
+impl Voted for Genre {
+    fn vote_count(&self) -> i64 {
+        i64::from(self.count)
+    }
+}
+
+/// Order tags or genres by vote count, e.g. for a tag cloud or a "top genres" list.
+pub trait SortedByCount<T> {
+    /// The tags or genres ordered by vote count, most voted first if `descending`. Equal counts
+    /// keep their original order.
+    fn sorted_by_count(&self, descending: bool) -> Vec<T>;
+}
+
+impl<T: Voted + Clone> SortedByCount<T> for [T] {
+    fn sorted_by_count(&self, descending: bool) -> Vec<T> {
+        let mut sorted = self.to_vec();
+        if descending {
+            sorted.sort_by_key(|voted| std::cmp::Reverse(voted.vote_count()));
+        } else {
+            sorted.sort_by_key(Voted::vote_count);
+        }
+        sorted
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, QueryBuilder)]
+pub struct TagSearchQuery {
+    /// (part of) the tag's name
+    pub tag: String,
+}
+
+impl_try_build!(TagSearchQueryLuceneQueryBuilder);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::search::SearchResult;
+
+    #[test]
+    fn should_search_releases_with_tag() {
+        let query = Tag::entities_with::<Release>("metal");
+
+        assert!(query.url().contains("/release?fmt=json&query=tag:metal"));
+
+        let input = r#"{
+            "created": "2024-06-01T00:00:00.000Z",
+            "count": 2,
+            "offset": 0,
+            "releases": [
+                {"id": "r1", "title": "Master of Puppets", "tags": [{"count": 3, "name": "metal"}]},
+                {"id": "r2", "title": "Paranoid", "tags": [{"count": 1, "name": "metal"}]}
+            ]
+        }"#;
+        let result: SearchResult<Release> = serde_json::from_str(input).unwrap();
+
+        assert_eq!(result.entities.len(), 2);
+        assert!(result.entities.iter().all(|release| release
+            .tags
+            .iter()
+            .flatten()
+            .any(|tag| tag.name == "metal")));
+    }
+
+    #[test]
+    fn should_deserialize_tag_search() {
+        let query = TagSearchQuery::query_builder().tag("metal").build();
+        assert!(Tag::search(query)
+            .url()
+            .contains("/tag?fmt=json&query=tag:metal"));
+
+        let input = r#"{
+            "created": "2024-06-01T00:00:00.000Z",
+            "count": 2,
+            "offset": 0,
+            "tags": [{"score": 100, "name": "metal"}, {"score": 80, "name": "death metal"}]
+        }"#;
+        let result: SearchResult<Tag> = serde_json::from_str(input).unwrap();
+
+        assert_eq!(result.entities[1].name, "death metal");
+        assert_eq!(result.entities[1].count, 0);
+    }
+
+    #[test]
+    fn should_sort_tags_and_genres_by_count() {
+        let tags: Vec<Tag> = serde_json::from_str(
+            r#"[{"count": 1, "name": "grunge"}, {"count": 12, "name": "rock"},
+                {"count": 4, "name": "punk"}, {"count": 4, "name": "alternative"}]"#,
+        )
+        .unwrap();
+
+        let names: Vec<_> = tags
+            .sorted_by_count(true)
+            .into_iter()
+            .map(|tag| tag.name)
+            .collect();
+        assert_eq!(names, vec!["rock", "punk", "alternative", "grunge"]);
+
+        let genres: Vec<Genre> = serde_json::from_str(
+            r#"[{"count": 3, "name": "grunge"}, {"count": 0, "name": "pop"},
+                {"count": 9, "name": "rock"}]"#,
+        )
+        .unwrap();
+
+        let counts: Vec<_> = genres
+            .sorted_by_count(false)
+            .iter()
+            .map(|genre| genre.count)
+            .collect();
+        assert_eq!(counts, vec![0, 3, 9]);
+    }
+}
@@ -1,4 +1,5 @@
 use super::{Include, Relationship};
+use crate::entity::mbid::Mbid;
 use crate::entity::tag::Tag;
 use serde::{Deserialize, Serialize};
 
@@ -6,12 +7,12 @@ use serde::{Deserialize, Serialize};
 /// A MusicBrainz URL entity can be edited to change the underlying internet URL it points to; and can
 /// be linked to Areas, Artists, Events, Instruments, Labels, Places, Recordings, Releases, Release
 /// Groups, and Series.
-
+///
 /// Take a look at the [relationship table](https://musicbrainz.org/relationships) on the MusicBrainz
 /// server to see all types.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 pub struct Url {
-    pub id: String,
+    pub id: Mbid,
     pub resource: String,
     pub tags: Option<Vec<Tag>>,
 }
@@ -1,11 +1,14 @@
 use super::{Include, Relationship, Subquery};
 use crate::entity::alias::Alias;
+use crate::entity::artist::Artist;
 use crate::entity::genre::Genre;
+use crate::entity::mbid::Mbid;
 use crate::entity::rating::Rating;
-use crate::entity::relations::Relation;
+use crate::entity::relations::{Relation, RelationContent};
+use crate::entity::release::Language;
 use crate::entity::tag::Tag;
 use crate::entity::BrowseBy;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
 use lucene_query_builder::QueryBuilder;
 
@@ -537,16 +540,20 @@ impl From<String> for MusicalKey {
 #[serde(rename_all(deserialize = "kebab-case"))]
 pub struct Work {
     /// See [MusicBrainz Identifier](https://musicbrainz.org/doc/MusicBrainz_Identifier).
-    pub id: String,
+    pub id: Mbid,
     pub title: String,
     pub type_id: Option<String>,
     /// Works are represented predominantly at two levels: Discrete works, Aggregate works.
     #[serde(rename = "type")]
     pub work_type: Option<WorkType>,
-    // Discuss: Is it reasonable to have a Language enum (which would almost certainly have up to thousands of variants)?
-    pub language: Option<String>,
-    pub languages: Option<Vec<String>>,
+    /// The language of the lyrics. Deprecated by MusicBrainz in favor of `languages`, and only
+    /// set when the work has a single language.
+    pub language: Option<Language>,
+    /// The languages of the lyrics. Use [`Work::lyrics_languages`] to read them whichever of
+    /// `languages` and `language` the response filled.
+    pub languages: Option<Vec<Language>>,
     pub iswcs: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "deserialize_attributes")]
     pub attributes: Option<Vec<WorkAttribute>>,
     /// The disambiguation comments are fields in the database used to help distinguish identically
     /// named artists, labels and other entities.
@@ -554,6 +561,8 @@ pub struct Work {
     pub relations: Option<Vec<Relation>>,
     pub tags: Option<Vec<Tag>>,
     pub rating: Option<Rating>,
+    /// The rating the authenticated user gave, loaded with the `user-ratings` include.
+    pub user_rating: Option<Rating>,
     /// If a discrete work is known by name(s) or in language(s) other than its canonical name,
     /// these are specified in the work’s aliases.
     pub aliases: Option<Vec<Alias>>,
@@ -564,6 +573,74 @@ pub struct Work {
     pub annotation: Option<String>,
 }
 
+/// Attribute types missing from [`WorkAttribute`] come with a value, which the `#[serde(other)]`
+/// unit variant can't hold: deserialize them one by one so they don't fail the whole work.
+fn deserialize_attributes<'de, D>(deserializer: D) -> Result<Option<Vec<WorkAttribute>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let attributes: Option<Vec<serde_json::Value>> = Option::deserialize(deserializer)?;
+    Ok(attributes.map(|attributes| {
+        attributes
+            .into_iter()
+            .map(|attribute| {
+                WorkAttribute::deserialize(attribute)
+                    .unwrap_or(WorkAttribute::UnrecognizedAttribute)
+            })
+            .collect()
+    }))
+}
+
+/// The work-artist relationship types of the artists who wrote the lyrics of a work.
+const LYRICIST_RELATIONS: &[&str] = &["lyricist", "librettist"];
+
+impl Work {
+    /// The artists who composed the work, in the order of the relations. Requires the work to be
+    /// fetched with [`with_artist_relations`](crate::FetchQuery::with_artist_relations).
+    pub fn composers(&self) -> Vec<&Artist> {
+        self.related_artists(&["composer"])
+    }
+
+    /// The artists who wrote the lyrics of the work: lyricists and librettists, in the order of
+    /// the relations. Requires the work to be fetched with
+    /// [`with_artist_relations`](crate::FetchQuery::with_artist_relations).
+    pub fn lyricists(&self) -> Vec<&Artist> {
+        self.related_artists(LYRICIST_RELATIONS)
+    }
+
+    fn related_artists(&self, relation_types: &[&str]) -> Vec<&Artist> {
+        self.relations
+            .iter()
+            .flatten()
+            .filter(|relation| relation_types.contains(&relation.relation_type.as_str()))
+            .filter_map(|relation| match &relation.content {
+                RelationContent::Artist(artist) => Some(artist.as_ref()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The languages of the lyrics, read from `languages`, or from the legacy `language` when
+    /// `languages` is missing or empty.
+    pub fn lyrics_languages(&self) -> Vec<&Language> {
+        match &self.languages {
+            Some(languages) if !languages.is_empty() => languages.iter().collect(),
+            _ => self.language.iter().collect(),
+        }
+    }
+
+    /// The musical key of the work, from its [`WorkAttribute::Key`] attribute.
+    pub fn key(&self) -> Option<&MusicalKey> {
+        self.attributes
+            .iter()
+            .flatten()
+            .find_map(|attribute| match attribute {
+                WorkAttribute::Key(key) => Some(key),
+                _ => None,
+            })
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize, QueryBuilder)]
 pub struct WorkSearchQuery {
     /// (part of) any alias attached to the work (diacritics are ignored)
@@ -598,6 +675,8 @@ pub struct WorkSearchQuery {
     pub work_accent: String,
 }
 
+impl_try_build!(WorkSearchQueryLuceneQueryBuilder);
+
 impl_browse! {
 Work,
    (by_artist, BrowseBy::Artist),
@@ -618,6 +697,10 @@ impl_includes!(
         with_recording_relations,
         Include::Relationship(Relationship::Recording)
     ),
+    (
+        with_series_relations,
+        Include::Relationship(Relationship::Series)
+    ),
     (with_url_relations, Include::Relationship(Relationship::Url)),
     (
         with_work_relations,
@@ -625,7 +708,177 @@ impl_includes!(
     ),
     (with_tags, Include::Subquery(Subquery::Tags)),
     (with_ratings, Include::Subquery(Subquery::Rating)),
+    (with_user_ratings, Include::Subquery(Subquery::UserRatings)),
     (with_aliases, Include::Subquery(Subquery::Aliases)),
     (with_genres, Include::Subquery(Subquery::Genres)),
     (with_annotations, Include::Subquery(Subquery::Annotations))
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_deserialize_work_tags() {
+        let json = r#"{
+            "id": "5d3b6fd8-22bc-3ae5-a5b5-f7c3b9a3ddfa",
+            "title": "Smells Like Teen Spirit",
+            "type": "Song",
+            "language": "eng",
+            "tags": [
+                {"name": "grunge", "count": 3},
+                {"name": "rock", "count": 1}
+            ],
+            "genres": [{"id": "cc38aba3-ba6d-4e5b-a7c1-f4d2f4e5ec23", "name": "grunge", "count": 3}]
+        }"#;
+
+        let work: Work = serde_json::from_str(json).unwrap();
+        let tags = work.tags.unwrap();
+
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[1].name, "rock");
+        assert_eq!(work.genres.unwrap()[0].name, "grunge");
+    }
+
+    #[test]
+    fn should_deserialize_classical_work_attributes() {
+        let json = r#"{
+            "id": "8b4a1b8a-3c4e-3f8c-9a52-5a1d3a4e8f0b",
+            "title": "Toccata and Fugue in D minor, BWV 565",
+            "type": "Song",
+            "language": "zxx",
+            "attributes": [
+                {"type": "Key", "type-id": "7526c19d-3be4-3420-b6cc-9fb6e49fa1a9", "value": "D minor", "value-id": "6b3ac4c0-0d3f-3b3e-b4b3-0a8e1b3546e4"},
+                {"type": "GEMA ID", "type-id": "01eeee67-f514-3801-bdce-279e2ea3ef30", "value": "1234567-001"},
+                {"type": "Not an attribute yet", "type-id": "00000000-0000-0000-0000-000000000000", "value": "?"}
+            ]
+        }"#;
+
+        let work: Work = serde_json::from_str(json).unwrap();
+        let attributes = work.attributes.as_ref().unwrap();
+
+        assert_eq!(work.key(), Some(&MusicalKey::DMinor));
+        assert_eq!(
+            attributes[1],
+            WorkAttribute::GemaId("1234567-001".to_string())
+        );
+        assert_eq!(attributes[2], WorkAttribute::UnrecognizedAttribute);
+    }
+
+    #[test]
+    fn should_deserialize_multilingual_work() {
+        let json = r#"{
+            "id": "4b8e5a5c-2e14-3a8a-8d27-7f3c9c6f9a1e",
+            "title": "Bailando",
+            "type": "Song",
+            "language": "mul",
+            "languages": ["spa", "eng", "xyz"]
+        }"#;
+
+        let work: Work = serde_json::from_str(json).unwrap();
+
+        assert_eq!(work.language, Some(Language::Mul));
+        assert_eq!(
+            work.lyrics_languages(),
+            vec![
+                &Language::Spa,
+                &Language::Eng,
+                &Language::UnrecognizedLanguage
+            ]
+        );
+
+        let legacy: Work = serde_json::from_str(
+            r#"{"id": "5d3b6fd8-22bc-3ae5-a5b5-f7c3b9a3ddfa", "title": "Smells Like Teen Spirit",
+                "language": "eng"}"#,
+        )
+        .unwrap();
+        assert_eq!(legacy.lyrics_languages(), vec![&Language::Eng]);
+    }
+
+    #[test]
+    fn should_deserialize_series_relations() {
+        let json = r#"{
+            "id": "2b6aa3d5-2b7d-3e5c-8a4e-5c9e9a3b8f1e",
+            "title": "Brandenburg Concerto no. 3 in G major, BWV 1048",
+            "relations": [
+                {
+                    "type": "part of",
+                    "type-id": "b0d44366-cdf0-3acb-bee6-0f65a77a6ef0",
+                    "direction": "forward",
+                    "target-type": "series",
+                    "ordering-key": 1048,
+                    "attributes": ["number"],
+                    "attribute-values": {"number": "BWV 1048"},
+                    "series": {
+                        "id": "4d0ea3b4-8e7e-4b2b-a0c8-0e5a0a3c9f11",
+                        "name": "Bach-Werke-Verzeichnis",
+                        "type": "Catalogue",
+                        "type-id": "49482ff0-fc9e-3b8c-a2d0-30e84d9df002",
+                        "disambiguation": ""
+                    }
+                }
+            ]
+        }"#;
+
+        let work: Work = serde_json::from_str(json).unwrap();
+        let relations = work.relations.unwrap();
+
+        assert_eq!(relations[0].target_type.as_deref(), Some("series"));
+        assert_eq!(relations[0].ordering_key.as_deref(), Some("1048"));
+        assert!(matches!(
+            &relations[0].content,
+            RelationContent::Series(series) if series.name == "Bach-Werke-Verzeichnis"
+        ));
+    }
+
+    #[test]
+    fn should_separate_composers_and_lyricists() {
+        let artist = |relation_type: &str, id: &str, name: &str| {
+            serde_json::json!({
+                "type": relation_type,
+                "type-id": "d59d99ea-23d4-4a80-b066-edca32ee158f",
+                "direction": "backward",
+                "target-type": "artist",
+                "attributes": [],
+                "artist": {"id": id, "name": name, "sort-name": name}
+            })
+        };
+        let work: Work = serde_json::from_value(serde_json::json!({
+            "id": "0d6b4f6e-5a3e-3b0e-8f3d-9e4a6b9c1a2b",
+            "title": "Your Song",
+            "relations": [
+                artist("lyricist", "a8c3b5e1-2f5d-4e6c-9a7b-1c2d3e4f5a6b", "Bernie Taupin"),
+                artist("composer", "b83bc61f-8451-4a5d-8b8e-7e9ed295e822", "Elton John"),
+                artist("arranger", "c2d3e4f5-a6b7-4c8d-9e0f-1a2b3c4d5e6f", "Paul Buckmaster"),
+                {
+                    "type": "performance",
+                    "type-id": "a3005666-a872-32c3-ad06-98af558e99b0",
+                    "direction": "backward",
+                    "target-type": "recording",
+                    "attributes": [],
+                    "recording": {"id": "d4e5f6a7-b8c9-4d0e-8f1a-2b3c4d5e6f7a", "title": "Your Song"}
+                }
+            ]
+        }))
+        .unwrap();
+
+        let composers: Vec<&str> = work.composers().iter().map(|a| a.name.as_str()).collect();
+        let lyricists: Vec<&str> = work.lyricists().iter().map(|a| a.name.as_str()).collect();
+
+        assert_eq!(composers, ["Elton John"]);
+        assert_eq!(lyricists, ["Bernie Taupin"]);
+    }
+
+    #[test]
+    fn should_request_series_relations() {
+        use crate::Fetch;
+
+        let mut query = Work::fetch();
+        query
+            .id("2b6aa3d5-2b7d-3e5c-8a4e-5c9e9a3b8f1e")
+            .with_series_relations();
+        query.params_to_path();
+
+        assert!(query.0.path.ends_with("?fmt=json&inc=series-rels"));
+    }
+}
@@ -0,0 +1,127 @@
+use crate::entity::mbid::InvalidMbid;
+use std::fmt;
+use std::time::Duration;
+
+/// Errors returned when querying the MusicBrainz API.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The id given to a fetch query is not a well-formed MBID. No request was sent.
+    InvalidMbid(InvalidMbid),
+    /// The limit given to [`config::set_default_limit`](crate::config::set_default_limit) is
+    /// not between 1 and 100.
+    InvalidLimit(u8),
+    /// `try_build` was called on a search query builder without setting any field.
+    EmptySearchQuery,
+    /// The ISNI given to a search query builder's `try_isni` is not 15 digits followed by a
+    /// digit or `X`.
+    InvalidIsni(String),
+    /// The url given to [`config::set_base_url`](crate::config::set_base_url) is not a valid
+    /// url, or doesn't use HTTPS while
+    /// [`config::set_https_only`](crate::config::set_https_only) is enabled.
+    InvalidBaseUrl(String),
+    /// MusicBrainz kept answering with `503 Service Unavailable` after all the retries (see
+    /// [`config::set_default_retries`](crate::config::set_default_retries)).
+    /// `retry_after` is the delay the server asked for, if it sent one.
+    RateLimited { retry_after: Option<Duration> },
+    /// The request could not be sent, or its response could not be read.
+    /// See [reqwest::Error].
+    Http(reqwest::Error),
+    /// The response body is not the JSON expected for the queried entity. Holds the error of
+    /// the parser in use (serde_json, or simd-json with the `simd-json` feature).
+    Json(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl Error {
+    /// The [reqwest::Error] behind an [`Error::Http`], for code written against the former
+    /// `Error = reqwest::Error` alias.
+    pub fn as_reqwest(&self) -> Option<&reqwest::Error> {
+        match self {
+            Error::Http(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Take the [reqwest::Error] out of an [`Error::Http`], or give the error back.
+    pub fn into_reqwest(self) -> Result<reqwest::Error, Self> {
+        match self {
+            Error::Http(err) => Ok(err),
+            err => Err(err),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidMbid(err) => write!(f, "{}", err),
+            Error::InvalidLimit(limit) => {
+                write!(
+                    f,
+                    "invalid limit {}, expected a value between 1 and 100",
+                    limit
+                )
+            }
+            Error::EmptySearchQuery => write!(f, "empty search query, set at least one field"),
+            Error::InvalidIsni(isni) => write!(f, "invalid ISNI {}", isni),
+            Error::InvalidBaseUrl(url) => {
+                write!(f, "invalid base url {}, expected an https url", url)
+            }
+            Error::RateLimited {
+                retry_after: Some(retry_after),
+            } => write!(
+                f,
+                "rate limited by MusicBrainz, retry after {}s",
+                retry_after.as_secs()
+            ),
+            Error::RateLimited { retry_after: None } => write!(f, "rate limited by MusicBrainz"),
+            Error::Http(err) => write!(f, "{}", err),
+            Error::Json(err) => write!(f, "unexpected response body: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::InvalidMbid(err) => Some(err),
+            Error::InvalidLimit(_)
+            | Error::EmptySearchQuery
+            | Error::InvalidIsni(_)
+            | Error::InvalidBaseUrl(_)
+            | Error::RateLimited { .. } => None,
+            Error::Http(err) => Some(err),
+            Error::Json(err) => Some(err.as_ref()),
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Error::Http(err)
+    }
+}
+
+impl From<InvalidMbid> for Error {
+    fn from(err: InvalidMbid) -> Self {
+        Error::InvalidMbid(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_expose_reqwest_error() {
+        let reqwest_err = reqwest::Client::new().get("not a url").build().unwrap_err();
+        let err = Error::from(reqwest_err);
+
+        assert!(err.as_reqwest().unwrap().is_builder());
+        assert!(err.into_reqwest().unwrap().is_builder());
+
+        let err = Error::from(InvalidMbid("nirvana".to_string()));
+        assert!(err.as_reqwest().is_none());
+        assert!(matches!(err.into_reqwest(), Err(Error::InvalidMbid(_))));
+    }
+}
@@ -0,0 +1,377 @@
+//! Export browse results as CSV or TSV, one flat row per entity.
+//!
+//! The columns of each entity are declared by its [`CsvRecord`](crate::export::CsvRecord)
+//! implementation. Nested lists (relations, tags, media ...) are not exported.
+//!
+//! ## Example
+//! ```rust
+//! use musicbrainz_rs_nova::entity::release_group::ReleaseGroup;
+//! use musicbrainz_rs_nova::entity::BrowseResult;
+//!
+//! let result: BrowseResult<ReleaseGroup> = serde_json::from_str(
+//!     r#"{"release-group-count": 1, "release-group-offset": 0, "release-groups": [
+//!         {"id": "1b022e01-4da6-387b-8658-8678046e4cef", "title": "Nevermind",
+//!          "primary-type": "Album", "first-release-date": "1991-09-24"}
+//!     ]}"#,
+//! ).unwrap();
+//!
+//! let mut csv = vec![];
+//! result.to_csv(&mut csv).unwrap();
+//! assert_eq!(
+//!     String::from_utf8(csv).unwrap(),
+//!     "id,title,primary_type,secondary_types,first_release_date,disambiguation\n\
+//!      1b022e01-4da6-387b-8658-8678046e4cef,Nevermind,Album,,1991-09-24,\n"
+//! );
+//! ```
+
+use std::io::{self, Write};
+use std::marker::PhantomData;
+
+use serde::Serialize;
+
+use crate::entity::artist::Artist;
+use crate::entity::label::Label;
+use crate::entity::recording::Recording;
+use crate::entity::release::Release;
+use crate::entity::release_group::ReleaseGroup;
+use crate::entity::work::Work;
+use crate::entity::BrowseResult;
+
+/// An entity that can be exported as a CSV row.
+pub trait CsvRecord {
+    /// The header row.
+    const COLUMNS: &'static [&'static str];
+
+    /// The values of the entity, in the same order as [`CsvRecord::COLUMNS`].
+    fn csv_row(&self) -> Vec<String>;
+}
+
+impl<T: CsvRecord> BrowseResult<T> {
+    /// Write the entities of this page as CSV, header row included.
+    /// See [`CsvExport`] to export all the pages of a browse query in a single file.
+    pub fn to_csv<W: Write>(&self, writer: W) -> io::Result<()> {
+        CsvExport::new(writer).write_page(self)
+    }
+
+    /// Write the entities of this page as TSV, header row included.
+    pub fn to_tsv<W: Write>(&self, writer: W) -> io::Result<()> {
+        CsvExport::tsv(writer).write_page(self)
+    }
+}
+
+/// Write browse results to a CSV file page by page, so the whole result set never needs to be
+/// held in memory. The header row is written before the first page.
+///
+/// ## Example
+/// ```rust
+/// # use musicbrainz_rs_nova::entity::release::Release;
+/// # use musicbrainz_rs_nova::entity::BrowseResult;
+/// use musicbrainz_rs_nova::export::CsvExport;
+///
+/// # let pages: Vec<BrowseResult<Release>> = vec![];
+/// let mut export = CsvExport::new(vec![]);
+/// for page in &pages {
+///     export.write_page(page).unwrap();
+/// }
+/// let csv = export.into_inner();
+/// ```
+#[derive(Debug)]
+pub struct CsvExport<W, T> {
+    writer: W,
+    delimiter: char,
+    header_written: bool,
+    phantom: PhantomData<T>,
+}
+
+impl<W: Write, T: CsvRecord> CsvExport<W, T> {
+    /// Export comma separated values to `writer`.
+    pub fn new(writer: W) -> Self {
+        CsvExport::with_delimiter(writer, ',')
+    }
+
+    /// Export tab separated values to `writer`.
+    pub fn tsv(writer: W) -> Self {
+        CsvExport::with_delimiter(writer, '\t')
+    }
+
+    /// Export values separated by `delimiter` to `writer`, e.g. `;` for spreadsheets using a
+    /// decimal comma.
+    pub fn with_delimiter(writer: W, delimiter: char) -> Self {
+        CsvExport {
+            writer,
+            delimiter,
+            header_written: false,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Append the entities of `page`, writing the header row first if needed.
+    pub fn write_page(&mut self, page: &BrowseResult<T>) -> io::Result<()> {
+        if !self.header_written {
+            write_record(&mut self.writer, self.delimiter, T::COLUMNS)?;
+            self.header_written = true;
+        }
+        for entity in &page.entities {
+            write_record(&mut self.writer, self.delimiter, &entity.csv_row())?;
+        }
+        self.writer.flush()
+    }
+
+    /// Get back the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+fn write_record<W: Write, S: AsRef<str>>(
+    writer: &mut W,
+    delimiter: char,
+    fields: &[S],
+) -> io::Result<()> {
+    let record = fields
+        .iter()
+        .map(|field| escape(field.as_ref(), delimiter))
+        .collect::<Vec<_>>()
+        .join(&delimiter.to_string());
+    writeln!(writer, "{}", record)
+}
+
+/// Quote a field containing the delimiter, a quote or a line break, doubling its quotes.
+fn escape(field: &str, delimiter: char) -> String {
+    if field.contains([delimiter, '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// The name MusicBrainz uses for an enum value, e.g. `Official` or `Jewel Case`.
+fn enum_cell<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => String::new(),
+    }
+}
+
+fn opt_cell<T: ToString>(value: &Option<T>) -> String {
+    value.as_ref().map(T::to_string).unwrap_or_default()
+}
+
+fn opt_enum_cell<T: Serialize>(value: &Option<T>) -> String {
+    value.as_ref().map(enum_cell).unwrap_or_default()
+}
+
+impl CsvRecord for Artist {
+    const COLUMNS: &'static [&'static str] = &[
+        "id",
+        "name",
+        "sort_name",
+        "type",
+        "country",
+        "disambiguation",
+    ];
+
+    fn csv_row(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            self.name.clone(),
+            self.sort_name.clone(),
+            opt_enum_cell(&self.artist_type),
+            opt_cell(&self.country),
+            self.disambiguation.clone(),
+        ]
+    }
+}
+
+impl CsvRecord for Release {
+    const COLUMNS: &'static [&'static str] = &[
+        "id",
+        "title",
+        "status",
+        "date",
+        "country",
+        "barcode",
+        "disambiguation",
+    ];
+
+    fn csv_row(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            self.title.clone(),
+            opt_enum_cell(&self.status),
+            opt_cell(&self.date),
+            opt_cell(&self.country),
+            opt_cell(&self.barcode),
+            opt_cell(&self.disambiguation),
+        ]
+    }
+}
+
+impl CsvRecord for ReleaseGroup {
+    const COLUMNS: &'static [&'static str] = &[
+        "id",
+        "title",
+        "primary_type",
+        "secondary_types",
+        "first_release_date",
+        "disambiguation",
+    ];
+
+    fn csv_row(&self) -> Vec<String> {
+        let secondary_types = self
+            .secondary_types
+            .iter()
+            .map(enum_cell)
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        vec![
+            self.id.to_string(),
+            self.title.clone(),
+            opt_enum_cell(&self.primary_type),
+            secondary_types,
+            opt_cell(&self.first_release_date),
+            self.disambiguation.clone(),
+        ]
+    }
+}
+
+impl CsvRecord for Recording {
+    const COLUMNS: &'static [&'static str] = &["id", "title", "length", "disambiguation"];
+
+    fn csv_row(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            self.title.clone(),
+            opt_cell(&self.length),
+            opt_cell(&self.disambiguation),
+        ]
+    }
+}
+
+impl CsvRecord for Label {
+    const COLUMNS: &'static [&'static str] = &[
+        "id",
+        "name",
+        "type",
+        "country",
+        "label_code",
+        "disambiguation",
+    ];
+
+    fn csv_row(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            self.name.clone(),
+            opt_enum_cell(&self.label_type),
+            opt_cell(&self.country),
+            opt_cell(&self.label_code),
+            opt_cell(&self.disambiguation),
+        ]
+    }
+}
+
+impl CsvRecord for Work {
+    const COLUMNS: &'static [&'static str] = &["id", "title", "type", "language", "disambiguation"];
+
+    fn csv_row(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            self.title.clone(),
+            opt_enum_cell(&self.work_type),
+            opt_cell(&self.language),
+            opt_cell(&self.disambiguation),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn releases(offset: i32, titles: &[&str]) -> BrowseResult<Release> {
+        let releases: Vec<_> = titles
+            .iter()
+            .map(|title| {
+                serde_json::json!({
+                    "id": "18d4e9b4-9247-4b44-914a-8ddec3502103",
+                    "title": title,
+                    "status": "Official",
+                    "date": "1989-06-15",
+                    "country": "US",
+                })
+            })
+            .collect();
+
+        serde_json::from_value(serde_json::json!({
+            "release-count": 3,
+            "release-offset": offset,
+            "releases": releases,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn should_export_browse_result_as_csv() {
+        let mut csv = vec![];
+        releases(0, &["Bleach", "Nevermind, Remastered"])
+            .to_csv(&mut csv)
+            .unwrap();
+
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "id,title,status,date,country,barcode,disambiguation"
+        );
+        assert_eq!(
+            lines[1],
+            "18d4e9b4-9247-4b44-914a-8ddec3502103,Bleach,Official,1989-06-15,US,,"
+        );
+        assert_eq!(
+            lines[2],
+            "18d4e9b4-9247-4b44-914a-8ddec3502103,\"Nevermind, Remastered\",Official,1989-06-15,US,,"
+        );
+    }
+
+    #[test]
+    fn should_write_header_once_across_pages() {
+        let mut export = CsvExport::new(vec![]);
+        export
+            .write_page(&releases(0, &["Bleach", "Nevermind"]))
+            .unwrap();
+        export.write_page(&releases(2, &["In Utero"])).unwrap();
+
+        let csv = String::from_utf8(export.into_inner()).unwrap();
+        assert_eq!(csv.lines().count(), 4);
+        assert_eq!(csv.matches("id,title").count(), 1);
+    }
+
+    #[test]
+    fn should_export_browse_result_as_tsv() {
+        let mut tsv = vec![];
+        releases(0, &["Nevermind, Remastered"])
+            .to_tsv(&mut tsv)
+            .unwrap();
+
+        let tsv = String::from_utf8(tsv).unwrap();
+        let lines: Vec<_> = tsv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "id\ttitle\tstatus\tdate\tcountry\tbarcode\tdisambiguation"
+        );
+        assert_eq!(
+            lines[1],
+            "18d4e9b4-9247-4b44-914a-8ddec3502103\tNevermind, Remastered\tOfficial\t1989-06-15\tUS\t\t"
+        );
+    }
+
+    #[test]
+    fn should_quote_special_characters() {
+        assert_eq!(escape("Bleach", ','), "Bleach");
+        assert_eq!(escape("12\" vinyl", ','), "\"12\"\" vinyl\"");
+        assert_eq!(escape("line\nbreak", ','), "\"line\nbreak\"");
+        assert_eq!(escape("tab\tseparated", '\t'), "\"tab\tseparated\"");
+        assert_eq!(escape("comma, separated", '\t'), "comma, separated");
+    }
+}
@@ -53,29 +53,59 @@ pub mod config;
 mod deserialization;
 /// All Musicbrainz entities
 pub mod entity;
+mod error;
+#[cfg(all(
+    feature = "dangerous",
+    not(any(feature = "default-tls", feature = "rustls"))
+))]
+compile_error!("the `dangerous` feature needs a TLS backend, enable `default-tls` or `rustls`");
+
+/// Export browse results as CSV
+#[cfg(feature = "export")]
+pub mod export;
+/// Answer queries with canned responses in tests
+#[cfg(feature = "mockable")]
+pub mod mock;
 /// Brings trait and type needed to perform any API query in scope
 pub mod prelude;
+/// Submit ISRCs and barcodes to MusicBrainz
+#[cfg(feature = "submission")]
+pub mod submission;
 
 /// Utilities for the rate_limiting
 #[cfg(feature = "rate_limit")]
 pub(crate) mod rate_limit;
 
+use crate::entity::mbid::{InvalidMbid, Mbid};
 use crate::entity::search::{SearchResult, Searchable};
+use deserialization::count_deserializer::{BrowseCount, SearchCount};
 use deserialization::date_format;
+use deserialization::json;
 use entity::Browsable;
 use entity::BrowseResult;
 use entity::Include;
 use entity::{CoverartResolution, CoverartResponse, CoverartTarget, CoverartType};
 use std::fmt::Write as _;
 
-/// Type alias for [reqwest::Error]
-pub type Error = reqwest::Error;
+#[cfg(feature = "blocking")]
+use reqwest::blocking::RequestBuilder;
+
+#[cfg(feature = "async")]
+use reqwest::RequestBuilder;
+
+pub use crate::error::Error;
 
 #[derive(Clone, Debug)]
 struct Query<T> {
     path: String,
     include: Vec<Include>,
+    /// The includes removed after MusicBrainz rejected them, see [`config::set_include_fallback`].
+    dropped_includes: Vec<&'static str>,
     phantom: PhantomData<T>,
+    invalid_id: Option<InvalidMbid>,
+    user_agent: Option<String>,
+    /// Overrides [`config::set_include_fallback`] for this query.
+    include_fallback: Option<bool>,
 }
 
 /// perform a lookup of an entity when you have the MBID for that entity
@@ -110,6 +140,14 @@ struct Query<T> {
 /// #   Ok(())
 /// # }
 /// ```
+///
+/// ## Subquery limits
+///
+/// Lists fetched through includes (e.g. an artist's releases or recordings) are capped by
+/// MusicBrainz at 25 entities, and the web service doesn't report how many exist in total.
+/// Use a [`BrowseQuery`] to page through all of them and get their `count`.
+/// The only exception is the tracklist of a release medium, see
+/// [`Media::is_tracklist_truncated`](crate::entity::release::Media::is_tracklist_truncated).
 #[derive(Clone, Debug)]
 pub struct FetchQuery<T>(Query<T>);
 
@@ -162,6 +200,8 @@ struct CoverartQuery<T> {
     path: String,
     target: CoverartTarget,
     phantom: PhantomData<T>,
+    invalid_id: Option<InvalidMbid>,
+    user_agent: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -263,14 +303,38 @@ pub struct BrowseQuery<T> {
 /// # }
 /// ```
 #[derive(Clone, Debug)]
-pub struct SearchQuery<T>(Query<T>);
+pub struct SearchQuery<T> {
+    inner: Query<T>,
+    offset: Option<u16>,
+    limit: Option<u8>,
+}
 
 impl<'a, T> FetchQuery<T>
 where
     T: Clone,
 {
-    pub fn id(&mut self, id: &str) -> &mut Self {
-        let _ = write!(self.0.path, "/{id}");
+    /// Set the MBID of the entity to lookup. A malformed MBID makes [`FetchQuery::execute`]
+    /// return [`Error::InvalidMbid`] without sending any request.
+    pub fn id(&mut self, id: impl AsRef<str>) -> &mut Self {
+        let id = id.as_ref();
+        if let Err(err) = id.parse::<Mbid>() {
+            self.0.invalid_id = Some(err);
+        }
+        let _ = write!(self.0.path, "/{}", id);
+        self
+    }
+
+    /// Override the User-Agent header for this request only.
+    /// See [`config::set_user_agent`] to set it for every request.
+    pub fn user_agent(&mut self, user_agent: &str) -> &mut Self {
+        self.0.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Enable or disable the include fallback for this request only.
+    /// See [`config::set_include_fallback`] to set it for every request.
+    pub fn include_fallback(&mut self, enabled: bool) -> &mut Self {
+        self.0.include_fallback = Some(enabled);
         self
     }
 
@@ -279,10 +343,25 @@ where
     where
         T: Fetch<'a> + DeserializeOwned,
     {
-        self.0.path.push_str(FMT_JSON);
-        self.include_to_path();
-        let request = HTTP_CLIENT.get(&self.0.path);
-        HTTP_CLIENT.send_with_retries(request)?.json()
+        let path = self.prepare()?;
+        let request = self.0.request();
+        #[cfg(feature = "mockable")]
+        if let Some(response) = mock::intercept(&request) {
+            return Ok(response);
+        }
+
+        let mut response = HTTP_CLIENT.send_with_retries(request)?;
+        while response.status() == reqwest::StatusCode::BAD_REQUEST
+            && self.0.include_fallback.unwrap_or_else(include_fallback)
+        {
+            let body = response.text()?;
+            match self.0.rejected_include(&body) {
+                Some(include) => self.drop_include(&path, include),
+                None => return json::from_bytes(body.into_bytes()),
+            }
+            response = HTTP_CLIENT.send_with_retries(self.0.request())?;
+        }
+        json::from_response(response)
     }
 
     #[cfg(feature = "async")]
@@ -290,13 +369,68 @@ where
     where
         T: Fetch<'a> + DeserializeOwned,
     {
-        self.0.path.push_str(FMT_JSON);
-        self.include_to_path();
-        let request = HTTP_CLIENT.get(&self.0.path);
-        HTTP_CLIENT.send_with_retries(request).await?.json().await
+        let path = self.prepare()?;
+        let request = self.0.request();
+        #[cfg(feature = "mockable")]
+        if let Some(response) = mock::intercept(&request) {
+            return Ok(response);
+        }
+
+        let mut response = HTTP_CLIENT.send_with_retries(request).await?;
+        while response.status() == reqwest::StatusCode::BAD_REQUEST
+            && self.0.include_fallback.unwrap_or_else(include_fallback)
+        {
+            let body = response.text().await?;
+            match self.0.rejected_include(&body) {
+                Some(include) => self.drop_include(&path, include),
+                None => return json::from_bytes(body.into_bytes()),
+            }
+            response = HTTP_CLIENT.send_with_retries(self.0.request()).await?;
+        }
+        json::from_response(response).await
     }
 
-    fn include_to_path(&mut self) {
+    /// The includes MusicBrainz rejected and that were dropped to retry the request, in the
+    /// order they were dropped. Always empty unless the include fallback is enabled, see
+    /// [`FetchQuery::include_fallback`].
+    pub fn dropped_includes(&self) -> &[&'static str] {
+        &self.0.dropped_includes
+    }
+
+    /// Check the MBID and complete the path the same way for both transports. Returns the path
+    /// before `fmt=json` and the includes were added.
+    fn prepare(&mut self) -> Result<String, Error> {
+        self.0.check_id()?;
+        let path = self.0.path.clone();
+        self.params_to_path();
+        Ok(path)
+    }
+
+    /// Remove a rejected include and rebuild the request path from `path`, the path before
+    /// `fmt=json` and the includes were added.
+    fn drop_include(&mut self, path: &str, include: Include) {
+        self.0.include.retain(|inc| inc != &include);
+        self.0.dropped_includes.push(include.as_str());
+        self.0.path = path.to_string();
+        self.params_to_path();
+    }
+
+    /// The url [`FetchQuery::execute`] will request, `fmt=json` and includes included.
+    pub fn url(&self) -> String {
+        let mut query = self.clone();
+        query.params_to_path();
+        query.0.path
+    }
+
+    /// The decoded query parameters [`FetchQuery::execute`] will send, in order, e.g.
+    /// `[("fmt", "json"), ("inc", "aliases tags")]`. Useful to log requests or send them through
+    /// your own HTTP client.
+    pub fn params(&self) -> Vec<(String, String)> {
+        url_params(&self.url())
+    }
+
+    fn params_to_path(&mut self) {
+        self.0.path.push_str(FMT_JSON);
         self.0.include_to_path()
     }
 }
@@ -305,8 +439,21 @@ impl<'a, T> FetchCoverartQuery<T>
 where
     T: Clone + FetchCoverart<'a>,
 {
-    pub fn id(&mut self, id: &str) -> &mut Self {
-        let _ = write!(self.0.path, "/{id}");
+    /// Set the MBID of the release or release group. A malformed MBID makes
+    /// [`FetchCoverartQuery::execute`] return [`Error::InvalidMbid`] without sending any request.
+    pub fn id(&mut self, id: impl AsRef<str>) -> &mut Self {
+        let id = id.as_ref();
+        if let Err(err) = id.parse::<Mbid>() {
+            self.0.invalid_id = Some(err);
+        }
+        let _ = write!(self.0.path, "/{}", id);
+        self
+    }
+
+    /// Override the User-Agent header for this request only.
+    /// See [`config::set_user_agent`] to set it for every request.
+    pub fn user_agent(&mut self, user_agent: &str) -> &mut Self {
+        self.0.user_agent = Some(user_agent.to_string());
         self
     }
 
@@ -363,35 +510,57 @@ where
         }
     }
 
+    /// Check the MBID and complete the path the same way for both transports.
+    fn prepare(&mut self) -> Result<(), Error> {
+        if let Some(err) = self.0.invalid_id.take() {
+            return Err(Error::InvalidMbid(err));
+        }
+        self.validate();
+        Ok(())
+    }
+
     #[cfg(feature = "blocking")]
     pub fn execute(&mut self) -> Result<CoverartResponse, Error> {
-        self.validate();
-        let request = HTTP_CLIENT.get(&self.0.path);
+        self.prepare()?;
+        let request = self.0.request();
         let response = HTTP_CLIENT.send_with_retries(request)?;
         let coverart_response = if self.0.target.img_type.is_some() {
             let url = response.url().clone();
             CoverartResponse::Url(url.to_string())
         } else {
-            CoverartResponse::Json(response.json()?)
+            CoverartResponse::Json(json::from_response(response)?)
         };
         Ok(coverart_response)
     }
 
     #[cfg(feature = "async")]
     pub async fn execute(&mut self) -> Result<CoverartResponse, Error> {
-        self.validate();
-        let request = HTTP_CLIENT.get(&self.0.path);
+        self.prepare()?;
+        let request = self.0.request();
         let response = HTTP_CLIENT.send_with_retries(request).await?;
         let coverart_response = if self.0.target.img_type.is_some() {
             let url = response.url().clone();
             CoverartResponse::Url(url.to_string())
         } else {
-            CoverartResponse::Json(response.json().await?)
+            CoverartResponse::Json(json::from_response(response).await?)
         };
         Ok(coverart_response)
     }
 }
 
+impl<T> CoverartQuery<T> {
+    fn request(&self) -> RequestBuilder {
+        with_user_agent(HTTP_CLIENT.get(&self.path), self.user_agent.as_deref())
+    }
+}
+
+fn with_user_agent(request: RequestBuilder, user_agent: Option<&str>) -> RequestBuilder {
+    match user_agent {
+        Some(user_agent) => request.header(reqwest::header::USER_AGENT, user_agent),
+        None => request,
+    }
+}
+
 impl<'a, T> BrowseQuery<T>
 where
     T: Clone,
@@ -402,8 +571,12 @@ where
         T: Fetch<'a> + DeserializeOwned + Browsable,
     {
         self.include_to_path();
-        let request = HTTP_CLIENT.get(&self.inner.path);
-        HTTP_CLIENT.send_with_retries(request)?.json()
+        let request = self.inner.request();
+        #[cfg(feature = "mockable")]
+        if let Some(response) = mock::intercept(&request) {
+            return Ok(response);
+        }
+        json::from_response(HTTP_CLIENT.send_with_retries(request)?)
     }
 
     #[cfg(feature = "async")]
@@ -412,22 +585,79 @@ where
         T: Fetch<'a> + DeserializeOwned + Browsable,
     {
         self.include_to_path();
-        let request = HTTP_CLIENT.get(&self.inner.path);
-        HTTP_CLIENT.send_with_retries(request).await?.json().await
+        let request = self.inner.request();
+        #[cfg(feature = "mockable")]
+        if let Some(response) = mock::intercept(&request) {
+            return Ok(response);
+        }
+        json::from_response(HTTP_CLIENT.send_with_retries(request).await?).await
     }
 
-    fn include_to_path(&mut self) {
-        self.inner.include_to_path();
-        if let Some(limit) = self.limit {
-            self.inner.path.push_str(PARAM_LIMIT);
-            self.inner.path.push_str(&limit.to_string());
+    /// The total number of entities the query matches, without downloading any of them: the
+    /// query is sent with `limit=0`.
+    #[cfg(feature = "blocking")]
+    pub fn count_only(&self) -> Result<u32, Error>
+    where
+        T: Browsable,
+    {
+        let request = self.count_request();
+        #[cfg(feature = "mockable")]
+        if let Some(result) = mock::intercept::<BrowseCount<T>>(&request) {
+            return Ok(result.count);
         }
-        if let Some(offset) = self.offset {
-            self.inner.path.push_str(PARAM_OFFSET);
-            self.inner.path.push_str(&offset.to_string());
+        let result: BrowseCount<T> = json::from_response(HTTP_CLIENT.send_with_retries(request)?)?;
+        Ok(result.count)
+    }
+
+    /// The total number of entities the query matches, without downloading any of them: the
+    /// query is sent with `limit=0`.
+    #[cfg(feature = "async")]
+    pub async fn count_only(&self) -> Result<u32, Error>
+    where
+        T: Browsable,
+    {
+        let request = self.count_request();
+        #[cfg(feature = "mockable")]
+        if let Some(result) = mock::intercept::<BrowseCount<T>>(&request) {
+            return Ok(result.count);
         }
+        let result: BrowseCount<T> =
+            json::from_response(HTTP_CLIENT.send_with_retries(request).await?).await?;
+        Ok(result.count)
+    }
+
+    fn count_request(&self) -> RequestBuilder {
+        let mut query = self.clone();
+        query.limit = Some(0);
+        query.include_to_path();
+        query.inner.request()
+    }
+
+    fn include_to_path(&mut self) {
+        self.inner.include_to_path();
+        paging_to_path(
+            &mut self.inner.path,
+            self.limit,
+            default_limit(),
+            self.offset,
+        );
+    }
+
+    /// The url [`BrowseQuery::execute`] will request, includes and paging included.
+    pub fn url(&self) -> String {
+        let mut query = self.clone();
+        query.include_to_path();
+        query.inner.path
     }
 
+    /// The decoded query parameters [`BrowseQuery::execute`] will send, in order.
+    /// See [`FetchQuery::params`].
+    pub fn params(&self) -> Vec<(String, String)> {
+        url_params(&self.url())
+    }
+
+    /// The maximum number of entities to return, between 1 and 100. Defaults to the
+    /// [`config::set_default_limit`] value if set, otherwise to 25.
     pub fn limit(&mut self, limit: u8) -> &mut Self {
         self.limit = Some(limit);
         self
@@ -437,6 +667,13 @@ where
         self.offset = Some(offset);
         self
     }
+
+    /// Override the User-Agent header for this request only.
+    /// See [`config::set_user_agent`] to set it for every request.
+    pub fn user_agent(&mut self, user_agent: &str) -> &mut Self {
+        self.inner.user_agent = Some(user_agent.to_string());
+        self
+    }
 }
 
 impl<'a, T> SearchQuery<T>
@@ -449,8 +686,12 @@ where
         T: Search<'a> + DeserializeOwned + Searchable,
     {
         self.include_to_path();
-        let request = HTTP_CLIENT.get(&self.0.path);
-        HTTP_CLIENT.send_with_retries(request)?.json()
+        let request = self.inner.request();
+        #[cfg(feature = "mockable")]
+        if let Some(response) = mock::intercept(&request) {
+            return Ok(response);
+        }
+        json::from_response(HTTP_CLIENT.send_with_retries(request)?)
     }
 
     #[cfg(feature = "async")]
@@ -459,32 +700,183 @@ where
         T: Search<'a> + DeserializeOwned + Searchable,
     {
         self.include_to_path();
-        let request = HTTP_CLIENT.get(&self.0.path);
-        HTTP_CLIENT.send_with_retries(request).await?.json().await
+        let request = self.inner.request();
+        #[cfg(feature = "mockable")]
+        if let Some(response) = mock::intercept(&request) {
+            return Ok(response);
+        }
+        json::from_response(HTTP_CLIENT.send_with_retries(request).await?).await
+    }
+
+    /// Override the User-Agent header for this request only.
+    /// See [`config::set_user_agent`] to set it for every request.
+    pub fn user_agent(&mut self, user_agent: &str) -> &mut Self {
+        self.inner.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// The total number of entities matching the query, without downloading any of them: the
+    /// query is sent with `limit=0`.
+    #[cfg(feature = "blocking")]
+    pub fn count_only(&self) -> Result<u32, Error>
+    where
+        T: Searchable,
+    {
+        let request = self.count_request();
+        #[cfg(feature = "mockable")]
+        if let Some(result) = mock::intercept::<SearchCount<T>>(&request) {
+            return Ok(result.count);
+        }
+        let result: SearchCount<T> = json::from_response(HTTP_CLIENT.send_with_retries(request)?)?;
+        Ok(result.count)
+    }
+
+    /// The total number of entities matching the query, without downloading any of them: the
+    /// query is sent with `limit=0`.
+    #[cfg(feature = "async")]
+    pub async fn count_only(&self) -> Result<u32, Error>
+    where
+        T: Searchable,
+    {
+        let request = self.count_request();
+        #[cfg(feature = "mockable")]
+        if let Some(result) = mock::intercept::<SearchCount<T>>(&request) {
+            return Ok(result.count);
+        }
+        let result: SearchCount<T> =
+            json::from_response(HTTP_CLIENT.send_with_retries(request).await?).await?;
+        Ok(result.count)
+    }
+
+    fn count_request(&self) -> RequestBuilder {
+        let mut query = self.clone();
+        query.limit = Some(0);
+        query.include_to_path();
+        query.inner.request()
     }
 
     fn include_to_path(&mut self) {
-        self.0.include_to_path()
+        self.inner.include_to_path();
+        paging_to_path(
+            &mut self.inner.path,
+            self.limit,
+            default_limit(),
+            self.offset,
+        );
+    }
+
+    /// The url [`SearchQuery::execute`] will request, the Lucene query and paging included.
+    pub fn url(&self) -> String {
+        let mut query = self.clone();
+        query.include_to_path();
+        query.inner.path
+    }
+
+    /// The decoded query parameters [`SearchQuery::execute`] will send, in order.
+    /// See [`FetchQuery::params`].
+    pub fn params(&self) -> Vec<(String, String)> {
+        url_params(&self.url())
+    }
+
+    /// The maximum number of entities to return, between 1 and 100. Defaults to the
+    /// [`config::set_default_limit`] value if set, otherwise to 25.
+    pub fn limit(&mut self, limit: u8) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(&mut self, offset: u16) -> &mut Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// Decode the query string of `url`. `+` separated values, like includes, decode to spaces.
+fn url_params(url: &str) -> Vec<(String, String)> {
+    reqwest::Url::parse(url)
+        .map(|url| {
+            url.query_pairs()
+                .map(|(name, value)| (name.into_owned(), value.into_owned()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Append the limit, or the `default` limit, and the offset to the query path.
+fn paging_to_path(path: &mut String, limit: Option<u8>, default: Option<u8>, offset: Option<u16>) {
+    if let Some(limit) = limit.or(default) {
+        path.push_str(PARAM_LIMIT);
+        path.push_str(&limit.to_string());
+    }
+    if let Some(offset) = offset {
+        path.push_str(PARAM_OFFSET);
+        path.push_str(&offset.to_string());
     }
 }
 
 impl<T> Query<T> {
+    fn request(&self) -> RequestBuilder {
+        let request = with_user_agent(HTTP_CLIENT.get(&self.path), self.user_agent.as_deref());
+        if self.needs_auth() {
+            return config::with_auth_token(request);
+        }
+        request
+    }
+
+    /// The user data includes are only sent back to the authenticated user, and private
+    /// collections can only be browsed by their owner.
+    fn needs_auth(&self) -> bool {
+        self.include
+            .contains(&Include::Subquery(entity::Subquery::UserRatings))
+            || url_params(&self.path)
+                .iter()
+                .any(|(name, _)| name == entity::BrowseBy::Collection.as_str())
+    }
+
+    /// Start the query string of a browse path, so parameters can be added in any order.
+    fn start_params(&mut self) {
+        if !self.path.contains('?') {
+            self.path.push_str(FMT_JSON);
+        }
+    }
+
+    fn check_id(&mut self) -> Result<(), Error> {
+        match self.invalid_id.take() {
+            Some(err) => Err(Error::InvalidMbid(err)),
+            None => Ok(()),
+        }
+    }
+
+    /// The requested include a `400 Bad Request` body complains about, e.g.
+    /// `{"error": "genres is not a valid inc parameter for the artist resource."}`.
+    fn rejected_include(&self, body: &str) -> Option<Include> {
+        let body: serde_json::Value = serde_json::from_str(body).ok()?;
+        let offending = body.get("error")?.as_str()?.split_whitespace().next()?;
+        self.include
+            .iter()
+            .find(|inc| inc.as_str() == offending)
+            .cloned()
+    }
+
     fn include(&mut self, include: Include) -> &mut Self {
         self.include.push(include);
         self
     }
 
+    /// Append all the includes as a single `inc=a+b+c` parameter, in the order they were
+    /// requested. Includes requested more than once are only sent once.
     fn include_to_path(&mut self) {
-        if !self.include.is_empty() {
-            self.path.push_str(PARAM_INC);
-        }
-
+        let mut includes: Vec<&str> = vec![];
         for inc in self.include.iter() {
-            self.path.push_str(inc.as_str());
-            if Some(inc) != self.include.last() {
-                self.path.push('+');
+            if !includes.contains(&inc.as_str()) {
+                includes.push(inc.as_str());
             }
         }
+
+        if !includes.is_empty() {
+            self.path.push_str(PARAM_INC);
+            self.path.push_str(&includes.join("+"));
+        }
     }
 }
 
@@ -499,14 +891,124 @@ pub trait Fetch<'a> {
     where
         Self: Sized + Path<'a>,
     {
-        FetchQuery(Query {
-            path: format!("{}/{}", BASE_URL, Self::path()),
-            phantom: PhantomData,
-            include: vec![],
-        })
+        fetch_query_at(&base_url())
+    }
+}
+
+/// A [`FetchQuery`] sent to `base_url` instead of the MusicBrainz web service.
+pub(crate) fn fetch_query_at<'a, T>(base_url: &str) -> FetchQuery<T>
+where
+    T: Path<'a>,
+{
+    FetchQuery(Query {
+        path: format!("{}/{}", base_url, T::path()),
+        phantom: PhantomData,
+        include: vec![],
+        dropped_includes: vec![],
+        invalid_id: None,
+        user_agent: None,
+        include_fallback: None,
+    })
+}
+
+/// Check whether the entity at `url` exists with a HEAD request: `404` maps to `false`, any other
+/// error status to `Err`.
+#[cfg(feature = "blocking")]
+pub(crate) fn exists_at(url: &str) -> Result<bool, Error> {
+    let response = HTTP_CLIENT.send_with_retries(HTTP_CLIENT.head(url))?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(false);
+    }
+    response.error_for_status()?;
+    Ok(true)
+}
+
+/// Check whether the entity at `url` exists with a HEAD request: `404` maps to `false`, any other
+/// error status to `Err`.
+#[cfg(feature = "async")]
+pub(crate) async fn exists_at(url: &str) -> Result<bool, Error> {
+    let response = HTTP_CLIENT.send_with_retries(HTTP_CLIENT.head(url)).await?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(false);
+    }
+    response.error_for_status()?;
+    Ok(true)
+}
+
+/// What [`service_info`] found out about the MusicBrainz web service.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceInfo {
+    /// The base url the requests are sent to.
+    pub base_url: String,
+    /// The version of the web service API, read from the base url (`2` for `/ws/2`).
+    /// MusicBrainz has no endpoint reporting it.
+    pub version: Option<String>,
+}
+
+impl ServiceInfo {
+    fn new(base_url: &str) -> Self {
+        ServiceInfo {
+            base_url: base_url.to_string(),
+            version: base_url
+                .rsplit_once("/ws/")
+                .map(|(_, version)| version.trim_matches('/').to_string()),
+        }
     }
 }
 
+/// The entity [`service_info`] probes: the "Various Artists" special purpose artist, which
+/// always exists.
+const PROBED_ARTIST: &str = "89ad4ac3-39f7-470e-963a-56509c546377";
+
+/// Check that the MusicBrainz web service is reachable, e.g. as a startup health check.
+///
+/// MusicBrainz has no status endpoint, so this sends a HEAD request for a well-known artist:
+/// any error status, `404` included, is returned as an `Err`.
+///
+/// ## Example
+/// ```rust
+/// # #[cfg(feature = "blocking")]
+/// # fn main() -> Result<(), musicbrainz_rs_nova::Error> {
+/// let info = musicbrainz_rs_nova::service_info()?;
+/// assert_eq!(info.version.as_deref(), Some("2"));
+/// #   Ok(())
+/// # }
+/// # #[cfg(feature = "async")]
+/// # fn main() {}
+/// ```
+#[cfg(feature = "blocking")]
+pub fn service_info() -> Result<ServiceInfo, Error> {
+    service_info_at(&base_url())
+}
+
+/// Check that the MusicBrainz web service is reachable, e.g. as a startup health check.
+///
+/// MusicBrainz has no status endpoint, so this sends a HEAD request for a well-known artist:
+/// any error status, `404` included, is returned as an `Err`.
+#[cfg(feature = "async")]
+pub async fn service_info() -> Result<ServiceInfo, Error> {
+    service_info_at(&base_url()).await
+}
+
+#[cfg(feature = "blocking")]
+fn service_info_at(base_url: &str) -> Result<ServiceInfo, Error> {
+    let url = format!("{}/artist/{}", base_url, PROBED_ARTIST);
+    HTTP_CLIENT
+        .send_with_retries(HTTP_CLIENT.head(&url))?
+        .error_for_status()?;
+    Ok(ServiceInfo::new(base_url))
+}
+
+#[cfg(feature = "async")]
+async fn service_info_at(base_url: &str) -> Result<ServiceInfo, Error> {
+    let url = format!("{}/artist/{}", base_url, PROBED_ARTIST);
+    HTTP_CLIENT
+        .send_with_retries(HTTP_CLIENT.head(&url))
+        .await?
+        .error_for_status()?;
+    Ok(ServiceInfo::new(base_url))
+}
+
 /// Implemented by all fetchable coverart entities (see [`FetchCoverartQuery`])
 pub trait FetchCoverart<'a> {
     fn fetch_coverart() -> FetchCoverartQuery<Self>
@@ -520,6 +1022,8 @@ pub trait FetchCoverart<'a> {
                 img_type: None,
                 img_res: None,
             },
+            invalid_id: None,
+            user_agent: None,
         })
     }
 
@@ -535,6 +1039,8 @@ pub trait FetchCoverart<'a> {
                 img_type: None,
                 img_res: None,
             },
+            invalid_id: None,
+            user_agent: None,
         })
     }
 }
@@ -545,15 +1051,27 @@ pub trait Browse<'a> {
     where
         Self: Sized + Path<'a>,
     {
-        BrowseQuery {
-            inner: Query {
-                path: format!("{}/{}", BASE_URL, Self::path()),
-                phantom: PhantomData,
-                include: vec![],
-            },
-            limit: None,
-            offset: None,
-        }
+        browse_query_at(&base_url())
+    }
+}
+
+/// A [`BrowseQuery`] sent to `base_url` instead of the MusicBrainz web service.
+pub(crate) fn browse_query_at<'a, T>(base_url: &str) -> BrowseQuery<T>
+where
+    T: Path<'a>,
+{
+    BrowseQuery {
+        inner: Query {
+            path: format!("{}/{}", base_url, T::path()),
+            phantom: PhantomData,
+            include: vec![],
+            dropped_includes: vec![],
+            invalid_id: None,
+            user_agent: None,
+            include_fallback: None,
+        },
+        limit: None,
+        offset: None,
     }
 }
 
@@ -563,10 +1081,630 @@ pub trait Search<'a> {
     where
         Self: Sized + Path<'a>,
     {
-        SearchQuery(Query {
-            path: format!("{}/{}{}&{}", BASE_URL, Self::path(), FMT_JSON, query),
+        search_query_at(&base_url(), query)
+    }
+}
+
+/// A [`SearchQuery`] sent to `base_url` instead of the MusicBrainz web service.
+pub(crate) fn search_query_at<'a, T>(base_url: &str, query: String) -> SearchQuery<T>
+where
+    T: Path<'a>,
+{
+    SearchQuery {
+        inner: Query {
+            path: format!("{}/{}{}&{}", base_url, T::path(), FMT_JSON, query),
+            phantom: PhantomData,
+            include: vec![],
+            dropped_includes: vec![],
+            invalid_id: None,
+            user_agent: None,
+            include_fallback: None,
+        },
+        limit: None,
+        offset: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::artist::{Artist, ArtistSearchQuery};
+    use crate::entity::release::Release;
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn should_reject_malformed_mbid_before_sending() {
+        let err = Artist::fetch().id("nirvana").execute().unwrap_err();
+        assert!(matches!(err, Error::InvalidMbid(InvalidMbid(id)) if id == "nirvana"));
+
+        let err = Release::fetch_coverart()
+            .id("in utero")
+            .execute()
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidMbid(_)));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_reject_malformed_mbid_before_sending() {
+        let err = Artist::fetch().id("nirvana").execute().await.unwrap_err();
+        assert!(matches!(err, Error::InvalidMbid(InvalidMbid(id)) if id == "nirvana"));
+
+        let err = Release::fetch_coverart()
+            .id("in utero")
+            .execute()
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidMbid(_)));
+    }
+
+    const NIRVANA_MBID: &str = "5b11f4ce-a62d-471e-81fc-a69a8278c7da";
+    const NIRVANA: &str = r#"{"id": "5b11f4ce-a62d-471e-81fc-a69a8278c7da", "name": "Nirvana", "sort-name": "Nirvana"}"#;
+
+    fn mock_fetch_query(server_url: &str) -> FetchQuery<Artist> {
+        FetchQuery(Query {
+            path: format!("{}/artist", server_url),
             phantom: PhantomData,
             include: vec![],
+            dropped_includes: vec![],
+            invalid_id: None,
+            user_agent: None,
+            include_fallback: None,
         })
     }
+
+    #[test]
+    fn should_send_includes_as_a_single_parameter() {
+        let mut query = mock_fetch_query("http://localhost");
+        query
+            .id(NIRVANA_MBID)
+            .with_aliases()
+            .with_tags()
+            .with_genres()
+            .with_tags();
+        query.params_to_path();
+
+        assert!(query.0.invalid_id.is_none());
+        assert_eq!(
+            query.0.path,
+            format!(
+                "http://localhost/artist/{}?fmt=json&inc=aliases+tags+genres",
+                NIRVANA_MBID
+            )
+        );
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn should_override_user_agent_for_one_request() {
+        let mut server = mockito::Server::new();
+        let overridden = server
+            .mock("GET", mockito::Matcher::Any)
+            .match_header("user-agent", "subsystem/1.0")
+            .with_body(NIRVANA)
+            .create();
+        let default = server
+            .mock("GET", mockito::Matcher::Any)
+            .match_header("user-agent", "musicbrainz_rs default")
+            .with_body(NIRVANA)
+            .create();
+
+        mock_fetch_query(&server.url())
+            .id(NIRVANA_MBID)
+            .user_agent("subsystem/1.0")
+            .execute()
+            .unwrap();
+        mock_fetch_query(&server.url())
+            .id(NIRVANA_MBID)
+            .execute()
+            .unwrap();
+
+        overridden.assert();
+        default.assert();
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_override_user_agent_for_one_request() {
+        let mut server = mockito::Server::new_async().await;
+        let overridden = server
+            .mock("GET", mockito::Matcher::Any)
+            .match_header("user-agent", "subsystem/1.0")
+            .with_body(NIRVANA)
+            .create_async()
+            .await;
+        let default = server
+            .mock("GET", mockito::Matcher::Any)
+            .match_header("user-agent", "musicbrainz_rs default")
+            .with_body(NIRVANA)
+            .create_async()
+            .await;
+
+        mock_fetch_query(&server.url())
+            .id(NIRVANA_MBID)
+            .user_agent("subsystem/1.0")
+            .execute()
+            .await
+            .unwrap();
+        mock_fetch_query(&server.url())
+            .id(NIRVANA_MBID)
+            .execute()
+            .await
+            .unwrap();
+
+        overridden.assert_async().await;
+        default.assert_async().await;
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn should_check_entity_existence() {
+        let mut server = mockito::Server::new();
+        let existing = server.mock("HEAD", "/artist/existing").create();
+        let missing = server
+            .mock("HEAD", "/artist/missing")
+            .with_status(404)
+            .create();
+        let broken = server
+            .mock("HEAD", "/artist/broken")
+            .with_status(500)
+            .create();
+
+        assert!(exists_at(&format!("{}/artist/existing", server.url())).unwrap());
+        assert!(!exists_at(&format!("{}/artist/missing", server.url())).unwrap());
+        assert!(exists_at(&format!("{}/artist/broken", server.url())).is_err());
+        assert!(matches!(
+            Artist::exists("nirvana"),
+            Err(Error::InvalidMbid(_))
+        ));
+
+        existing.assert();
+        missing.assert();
+        broken.assert();
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_check_entity_existence() {
+        let mut server = mockito::Server::new_async().await;
+        let existing = server.mock("HEAD", "/artist/existing").create_async().await;
+        let missing = server
+            .mock("HEAD", "/artist/missing")
+            .with_status(404)
+            .create_async()
+            .await;
+        let broken = server
+            .mock("HEAD", "/artist/broken")
+            .with_status(500)
+            .create_async()
+            .await;
+
+        let url = server.url();
+        assert!(exists_at(&format!("{}/artist/existing", url))
+            .await
+            .unwrap());
+        assert!(!exists_at(&format!("{}/artist/missing", url)).await.unwrap());
+        assert!(exists_at(&format!("{}/artist/broken", url)).await.is_err());
+        assert!(matches!(
+            Artist::exists("nirvana").await,
+            Err(Error::InvalidMbid(_))
+        ));
+
+        existing.assert_async().await;
+        missing.assert_async().await;
+        broken.assert_async().await;
+    }
+
+    #[test]
+    fn should_apply_default_limit_unless_overridden() {
+        let mut path = String::new();
+        paging_to_path(&mut path, None, Some(100), None);
+        assert_eq!(path, "&limit=100");
+
+        let mut path = String::new();
+        paging_to_path(&mut path, Some(10), Some(100), Some(20));
+        assert_eq!(path, "&limit=10&offset=20");
+
+        let mut path = String::new();
+        paging_to_path(&mut path, None, None, None);
+        assert!(path.is_empty());
+
+        assert!(matches!(
+            config::set_default_limit(0),
+            Err(Error::InvalidLimit(0))
+        ));
+        assert!(config::set_default_limit(101).is_err());
+    }
+
+    fn params(params: &[(&str, &str)]) -> Vec<(String, String)> {
+        params
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn should_expose_query_params() {
+        let mut fetch = Artist::fetch();
+        fetch.id(NIRVANA_MBID).with_aliases().with_tags();
+
+        assert_eq!(
+            fetch.url(),
+            format!(
+                "{}/artist/{}?fmt=json&inc=aliases+tags",
+                BASE_URL, NIRVANA_MBID
+            )
+        );
+        assert_eq!(
+            fetch.params(),
+            params(&[("fmt", "json"), ("inc", "aliases tags")])
+        );
+        // Building the params doesn't change the query.
+        assert_eq!(fetch.params(), fetch.params());
+
+        let mut browse = Release::browse();
+        browse.by_artist(NIRVANA_MBID).limit(10).offset(20);
+
+        assert_eq!(
+            browse.params(),
+            params(&[
+                ("fmt", "json"),
+                ("artist", NIRVANA_MBID),
+                ("limit", "10"),
+                ("offset", "20"),
+            ])
+        );
+
+        let query = ArtistSearchQuery::query_builder()
+            .artist("Nirvana")
+            .and()
+            .country("US")
+            .build();
+        let mut search = Artist::search(query);
+        search.limit(5);
+
+        assert_eq!(
+            search.params(),
+            params(&[
+                ("fmt", "json"),
+                ("query", "artist:Nirvana AND country:US"),
+                ("limit", "5"),
+            ])
+        );
+    }
+
+    const GENRES_REJECTED: &str =
+        r#"{"error": "genres is not a valid inc parameter for the artist resource."}"#;
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn should_retry_without_rejected_include() {
+        let mut server = mockito::Server::new();
+        let rejected = server
+            .mock("GET", mockito::Matcher::Any)
+            .match_query(mockito::Matcher::Regex("inc=aliases\\+genres$".to_string()))
+            .with_status(400)
+            .with_body(GENRES_REJECTED)
+            .create();
+        let accepted = server
+            .mock("GET", mockito::Matcher::Any)
+            .match_query(mockito::Matcher::Regex("inc=aliases$".to_string()))
+            .with_body(NIRVANA)
+            .create();
+
+        let mut query = mock_fetch_query(&server.url());
+        let nirvana = query
+            .id(NIRVANA_MBID)
+            .with_aliases()
+            .with_genres()
+            .include_fallback(true)
+            .execute()
+            .unwrap();
+
+        rejected.assert();
+        accepted.assert();
+        assert_eq!(nirvana.name, "Nirvana");
+        assert_eq!(query.dropped_includes(), ["genres"]);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_retry_without_rejected_include() {
+        let mut server = mockito::Server::new_async().await;
+        let rejected = server
+            .mock("GET", mockito::Matcher::Any)
+            .match_query(mockito::Matcher::Regex("inc=aliases\\+genres$".to_string()))
+            .with_status(400)
+            .with_body(GENRES_REJECTED)
+            .create_async()
+            .await;
+        let accepted = server
+            .mock("GET", mockito::Matcher::Any)
+            .match_query(mockito::Matcher::Regex("inc=aliases$".to_string()))
+            .with_body(NIRVANA)
+            .create_async()
+            .await;
+
+        let mut query = mock_fetch_query(&server.url());
+        let nirvana = query
+            .id(NIRVANA_MBID)
+            .with_aliases()
+            .with_genres()
+            .include_fallback(true)
+            .execute()
+            .await
+            .unwrap();
+
+        rejected.assert_async().await;
+        accepted.assert_async().await;
+        assert_eq!(nirvana.name, "Nirvana");
+        assert_eq!(query.dropped_includes(), ["genres"]);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn should_probe_web_service() {
+        let mut server = mockito::Server::new();
+        let probe = server
+            .mock("HEAD", "/ws/2/artist/89ad4ac3-39f7-470e-963a-56509c546377")
+            .create();
+        let down = server
+            .mock("HEAD", "/down/artist/89ad4ac3-39f7-470e-963a-56509c546377")
+            .with_status(502)
+            .create();
+
+        let info = service_info_at(&format!("{}/ws/2", server.url())).unwrap();
+        let err = service_info_at(&format!("{}/down", server.url())).unwrap_err();
+
+        probe.assert();
+        down.assert();
+        assert_eq!(info.base_url, format!("{}/ws/2", server.url()));
+        assert_eq!(info.version.as_deref(), Some("2"));
+        assert!(matches!(err, Error::Http(_)));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_probe_web_service() {
+        let mut server = mockito::Server::new_async().await;
+        let probe = server
+            .mock("HEAD", "/ws/2/artist/89ad4ac3-39f7-470e-963a-56509c546377")
+            .create_async()
+            .await;
+        let down = server
+            .mock("HEAD", "/down/artist/89ad4ac3-39f7-470e-963a-56509c546377")
+            .with_status(502)
+            .create_async()
+            .await;
+
+        let info = service_info_at(&format!("{}/ws/2", server.url()))
+            .await
+            .unwrap();
+        let err = service_info_at(&format!("{}/down", server.url()))
+            .await
+            .unwrap_err();
+
+        probe.assert_async().await;
+        down.assert_async().await;
+        assert_eq!(info.base_url, format!("{}/ws/2", server.url()));
+        assert_eq!(info.version.as_deref(), Some("2"));
+        assert!(matches!(err, Error::Http(_)));
+    }
+
+    const NO_RELEASES: &str = r#"{"release-count": 42, "release-offset": 0, "releases": []}"#;
+    const NO_ARTISTS: &str =
+        r#"{"created": "2024-06-01T00:00:00.000Z", "count": 7, "offset": 0, "artists": []}"#;
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn should_count_without_entities() {
+        let mut server = mockito::Server::new();
+        let browse = server
+            .mock("GET", "/release")
+            .match_query(mockito::Matcher::UrlEncoded("limit".into(), "0".into()))
+            .with_body(NO_RELEASES)
+            .create();
+        let search = server
+            .mock("GET", "/artist")
+            .match_query(mockito::Matcher::UrlEncoded("limit".into(), "0".into()))
+            .with_body(NO_ARTISTS)
+            .create();
+
+        let mut releases = browse_query_at::<Release>(&server.url());
+        releases.by_artist(NIRVANA_MBID).limit(10);
+        let query = ArtistSearchQuery::query_builder().artist("Nirvana").build();
+
+        assert_eq!(releases.count_only().unwrap(), 42);
+        assert_eq!(
+            search_query_at::<Artist>(&server.url(), query)
+                .count_only()
+                .unwrap(),
+            7
+        );
+        browse.assert();
+        search.assert();
+        // The query itself keeps its limit.
+        assert!(releases.url().ends_with("&limit=10"));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_count_without_entities() {
+        let mut server = mockito::Server::new_async().await;
+        let browse = server
+            .mock("GET", "/release")
+            .match_query(mockito::Matcher::UrlEncoded("limit".into(), "0".into()))
+            .with_body(NO_RELEASES)
+            .create_async()
+            .await;
+        let search = server
+            .mock("GET", "/artist")
+            .match_query(mockito::Matcher::UrlEncoded("limit".into(), "0".into()))
+            .with_body(NO_ARTISTS)
+            .create_async()
+            .await;
+
+        let mut releases = browse_query_at::<Release>(&server.url());
+        releases.by_artist(NIRVANA_MBID).limit(10);
+        let query = ArtistSearchQuery::query_builder().artist("Nirvana").build();
+
+        assert_eq!(releases.count_only().await.unwrap(), 42);
+        assert_eq!(
+            search_query_at::<Artist>(&server.url(), query)
+                .count_only()
+                .await
+                .unwrap(),
+            7
+        );
+        browse.assert_async().await;
+        search.assert_async().await;
+        // The query itself keeps its limit.
+        assert!(releases.url().ends_with("&limit=10"));
+    }
+
+    // Compiled under both the `async` and `blocking` features: only `execute` depends on the
+    // transport, so every query must build, and send, the same url whichever one is enabled.
+    fn fetch(base_url: &str) -> FetchQuery<Artist> {
+        let mut query: FetchQuery<Artist> = fetch_query_at(base_url);
+        query
+            .id(NIRVANA_MBID)
+            .with_aliases()
+            .with_tags()
+            .user_agent("parity/1.0");
+        query
+    }
+
+    fn browse(base_url: &str) -> BrowseQuery<Release> {
+        let mut query: BrowseQuery<Release> = browse_query_at(base_url);
+        query
+            .by_artist(NIRVANA_MBID)
+            .with_labels()
+            .limit(10)
+            .offset(20)
+            .user_agent("parity/1.0");
+        query
+    }
+
+    fn search(base_url: &str) -> SearchQuery<Artist> {
+        let query = ArtistSearchQuery::query_builder().artist("Nirvana").build();
+        let mut query = search_query_at(base_url, query);
+        query.limit(5).offset(10).user_agent("parity/1.0");
+        query
+    }
+
+    /// Match a request on the path and the raw query string of `url`.
+    fn sent_to(mock: mockito::Mock, url: &str) -> mockito::Mock {
+        let url = reqwest::Url::parse(url).unwrap();
+        mock.match_query(mockito::Matcher::Exact(
+            url.query().unwrap_or_default().to_string(),
+        ))
+        .match_header("user-agent", "parity/1.0")
+    }
+
+    fn path(url: &str) -> String {
+        reqwest::Url::parse(url).unwrap().path().to_string()
+    }
+
+    #[test]
+    fn should_build_identical_urls() {
+        assert_eq!(
+            fetch("http://localhost").url(),
+            format!(
+                "http://localhost/artist/{}?fmt=json&inc=aliases+tags",
+                NIRVANA_MBID
+            )
+        );
+        assert_eq!(
+            browse("http://localhost").url(),
+            format!(
+                "http://localhost/release?fmt=json&artist={}&inc=labels&limit=10&offset=20",
+                NIRVANA_MBID
+            )
+        );
+        assert_eq!(
+            search("http://localhost").url(),
+            "http://localhost/artist?fmt=json&query=artist:Nirvana&limit=5&offset=10"
+        );
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn should_send_the_built_urls() {
+        let mut server = mockito::Server::new();
+        let (fetch, browse, search) = (
+            fetch(&server.url()),
+            browse(&server.url()),
+            search(&server.url()),
+        );
+        let mocks = [
+            sent_to(
+                server.mock("GET", path(&fetch.url()).as_str()),
+                &fetch.url(),
+            )
+            .with_body(NIRVANA)
+            .create(),
+            sent_to(
+                server.mock("GET", path(&browse.url()).as_str()),
+                &browse.url(),
+            )
+            .with_body(NO_RELEASES)
+            .create(),
+            sent_to(
+                server.mock("GET", path(&search.url()).as_str()),
+                &search.url(),
+            )
+            .with_body(NO_ARTISTS)
+            .create(),
+        ];
+
+        fetch.clone().execute().unwrap();
+        browse.clone().execute().unwrap();
+        search.clone().execute().unwrap();
+
+        for mock in mocks {
+            mock.assert();
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_send_the_built_urls() {
+        let mut server = mockito::Server::new_async().await;
+        let (fetch, browse, search) = (
+            fetch(&server.url()),
+            browse(&server.url()),
+            search(&server.url()),
+        );
+        let mocks = [
+            sent_to(
+                server.mock("GET", path(&fetch.url()).as_str()),
+                &fetch.url(),
+            )
+            .with_body(NIRVANA)
+            .create_async()
+            .await,
+            sent_to(
+                server.mock("GET", path(&browse.url()).as_str()),
+                &browse.url(),
+            )
+            .with_body(NO_RELEASES)
+            .create_async()
+            .await,
+            sent_to(
+                server.mock("GET", path(&search.url()).as_str()),
+                &search.url(),
+            )
+            .with_body(NO_ARTISTS)
+            .create_async()
+            .await,
+        ];
+
+        fetch.clone().execute().await.unwrap();
+        browse.clone().execute().await.unwrap();
+        search.clone().execute().await.unwrap();
+
+        for mock in mocks {
+            mock.assert_async().await;
+        }
+    }
 }
@@ -0,0 +1,228 @@
+//! Unit test code calling MusicBrainz without a live server.
+//!
+//! Once a [`MockClient`](crate::mock::MockClient) is installed, fetch, browse and search
+//! queries executed on the same thread don't send anything: the request is recorded and the next
+//! queued JSON response is deserialized instead. With the `async` feature, run your tests on a
+//! current thread runtime (the `#[tokio::test]` default) so the query runs on the thread the mock
+//! is installed on.
+//!
+//! ## Example
+//! ```rust
+//! # use musicbrainz_rs_nova::prelude::*;
+//! # use musicbrainz_rs_nova::entity::artist::Artist;
+//! use musicbrainz_rs_nova::mock::MockClient;
+//!
+//! # #[cfg(feature = "blocking")]
+//! # fn main() {
+//! let mock = MockClient::install();
+//! mock.push_json(serde_json::json!({
+//!     "id": "5b11f4ce-a62d-471e-81fc-a69a8278c7da",
+//!     "name": "Nirvana"
+//! }));
+//!
+//! let nirvana = Artist::fetch()
+//!     .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+//!     .execute()
+//!     .unwrap();
+//!
+//! assert_eq!(nirvana.name, "Nirvana");
+//! assert!(mock.requests()[0].url.ends_with("/artist/5b11f4ce-a62d-471e-81fc-a69a8278c7da?fmt=json"));
+//! MockClient::uninstall();
+//! # }
+//! # #[cfg(feature = "async")]
+//! # fn main() {}
+//! ```
+
+use serde::de::DeserializeOwned;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+#[cfg(feature = "blocking")]
+use reqwest::blocking::RequestBuilder;
+
+#[cfg(feature = "async")]
+use reqwest::RequestBuilder;
+
+thread_local! {
+    static MOCK: RefCell<Option<MockClient>> = const { RefCell::new(None) };
+}
+
+/// A request intercepted by a [`MockClient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockRequest {
+    /// The full url, including the includes, paging and `fmt=json` parameters.
+    pub url: String,
+    /// The headers set on this request only, e.g. a
+    /// [`user_agent`](crate::FetchQuery::user_agent) override. Headers set on the client, like
+    /// the global User-Agent, are not listed.
+    pub headers: Vec<(String, String)>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    responses: VecDeque<serde_json::Value>,
+    requests: Vec<MockRequest>,
+}
+
+/// Answers queries with queued JSON responses instead of sending them. See the
+/// [module documentation](self).
+#[derive(Debug, Clone, Default)]
+pub struct MockClient {
+    state: Rc<RefCell<MockState>>,
+}
+
+impl MockClient {
+    /// Install a new mock for the current thread, replacing any previously installed one.
+    pub fn install() -> MockClient {
+        let mock = MockClient::default();
+        MOCK.with(|current| *current.borrow_mut() = Some(mock.clone()));
+        mock
+    }
+
+    /// Remove the mock installed on the current thread: queries are sent to MusicBrainz again.
+    pub fn uninstall() {
+        MOCK.with(|current| *current.borrow_mut() = None);
+    }
+
+    /// Queue the JSON body answering the next query.
+    pub fn push_json(&self, response: serde_json::Value) -> &Self {
+        self.state.borrow_mut().responses.push_back(response);
+        self
+    }
+
+    /// The requests intercepted so far, in order.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.state.borrow().requests.clone()
+    }
+}
+
+/// Answer `request` with the next queued response if a mock is installed.
+///
+/// # Panics
+///
+/// When no response is queued, or when it doesn't deserialize to `T`: both are mistakes in the
+/// test setup.
+pub(crate) fn intercept<T: DeserializeOwned>(request: &RequestBuilder) -> Option<T> {
+    let mock = MOCK.with(|current| current.borrow().clone())?;
+    let request = request
+        .try_clone()
+        .and_then(|request| request.build().ok())
+        .expect("MockClient: unable to build the request");
+
+    let mut state = mock.state.borrow_mut();
+    state.requests.push(MockRequest {
+        url: request.url().to_string(),
+        headers: request
+            .headers()
+            .iter()
+            .map(|(name, value)| {
+                let value = value.to_str().unwrap_or_default();
+                (name.to_string(), value.to_string())
+            })
+            .collect(),
+    });
+
+    let response = state
+        .responses
+        .pop_front()
+        .unwrap_or_else(|| panic!("MockClient: no response queued for {}", request.url()));
+    Some(
+        serde_json::from_value(response)
+            .unwrap_or_else(|err| panic!("MockClient: unexpected queued response: {}", err)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::artist::{Artist, ArtistSearchQuery};
+    use crate::entity::release::Release;
+    use crate::prelude::*;
+
+    fn fixtures() -> MockClient {
+        let mock = MockClient::install();
+        mock.push_json(serde_json::json!({
+            "id": "5b11f4ce-a62d-471e-81fc-a69a8278c7da",
+            "name": "Nirvana"
+        }))
+        .push_json(serde_json::json!({
+            "release-count": 1,
+            "release-offset": 0,
+            "releases": [{"id": "18d4e9b4-9247-4b44-914a-8ddec3502103", "title": "Bleach"}]
+        }))
+        .push_json(serde_json::json!({
+            "created": "2024-06-01T00:00:00.000Z",
+            "count": 1,
+            "offset": 0,
+            "artists": [{"id": "5b11f4ce-a62d-471e-81fc-a69a8278c7da", "name": "Nirvana"}]
+        }));
+        mock
+    }
+
+    fn assert_requests(mock: &MockClient) {
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 3);
+        assert!(requests[0]
+            .url
+            .ends_with("/artist/5b11f4ce-a62d-471e-81fc-a69a8278c7da?fmt=json"));
+        assert_eq!(
+            requests[0].headers,
+            vec![("user-agent".to_string(), "my_test/1.0".to_string())]
+        );
+        assert!(requests[1]
+            .url
+            .contains("artist=5b11f4ce-a62d-471e-81fc-a69a8278c7da"));
+        assert!(requests[2].url.contains("query=artist:Nirvana"));
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn should_answer_queries_with_queued_responses() {
+        let mock = fixtures();
+
+        let artist = Artist::fetch()
+            .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+            .user_agent("my_test/1.0")
+            .execute()
+            .unwrap();
+        let releases = Release::browse()
+            .by_artist("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+            .execute()
+            .unwrap();
+        let query = ArtistSearchQuery::query_builder().artist("Nirvana").build();
+        let search = Artist::search(query).execute().unwrap();
+        MockClient::uninstall();
+
+        assert_eq!(artist.name, "Nirvana");
+        assert_eq!(releases.entities[0].title, "Bleach");
+        assert_eq!(search.count, 1);
+        assert_requests(&mock);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_answer_queries_with_queued_responses() {
+        let mock = fixtures();
+
+        let artist = Artist::fetch()
+            .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+            .user_agent("my_test/1.0")
+            .execute()
+            .await
+            .unwrap();
+        let releases = Release::browse()
+            .by_artist("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+            .execute()
+            .await
+            .unwrap();
+        let query = ArtistSearchQuery::query_builder().artist("Nirvana").build();
+        let search = Artist::search(query).execute().await.unwrap();
+        MockClient::uninstall();
+
+        assert_eq!(artist.name, "Nirvana");
+        assert_eq!(releases.entities[0].title, "Bleach");
+        assert_eq!(search.count, 1);
+        assert_requests(&mock);
+    }
+}
@@ -1,3 +1,7 @@
+pub use crate::entity::lifespan::Lifespanned;
+pub use crate::entity::mbid::{Identified, Mbid};
+pub use crate::entity::rating::Rated;
+pub use crate::entity::tag::SortedByCount;
 pub use crate::Browse;
 pub use crate::Error;
 pub use crate::Fetch;
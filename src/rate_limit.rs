@@ -6,6 +6,9 @@ use tokio::sync::Mutex;
 use tokio::time::sleep_until;
 use tokio::time::Instant;
 
+/// The next request window. There is a single one for the whole process: every query, from any
+/// task or thread, waits on the same window, so concurrent callers never collectively exceed the
+/// limit.
 static RATE_LIMIT_NEXT_SPOT: Lazy<Arc<Mutex<Instant>>> =
     Lazy::new(|| Arc::new(Mutex::new(Instant::now())));
 
@@ -25,3 +28,22 @@ pub(super) async fn wait_for_ratelimit() {
     // We set the next window to be the next second. According to MB's documentation, the user should limit itself to 1 request / second
     *next_slot = Instant::now() + Duration::from_secs(1);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn should_share_rate_limit_between_concurrent_tasks() {
+        let start = Instant::now();
+
+        let tasks: Vec<_> = (0..3).map(|_| tokio::spawn(wait_for_ratelimit())).collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        // The first request may go through right away, the two others must each wait for
+        // their own window.
+        assert!(start.elapsed() >= Duration::from_secs(2));
+    }
+}
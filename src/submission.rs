@@ -0,0 +1,457 @@
+//! MusicBrainz only accepts two kind of submissions through its web service: ISRCs on
+//! recordings and barcodes on releases. Both require an OAuth2 token set with
+//! [`set_auth_token`](crate::config::set_auth_token).
+//!
+//! Submissions are sent to the base url set with [`set_base_url`](crate::config::set_base_url),
+//! so a client pointed at a mirror or a test server never edits musicbrainz.org. Keep it on
+//! HTTPS: musicbrainz.org redirects plain HTTP requests, and the redirected request would lose
+//! its body.
+//!
+//! Unlike queries, submissions are never retried: a rate limited submission fails with
+//! [`Error::RateLimited`](crate::Error::RateLimited), and it is up to the caller to send it
+//! again.
+//!
+//! See [Submitting data](https://musicbrainz.org/doc/MusicBrainz_API#Submitting_data)
+
+use std::fmt;
+
+use crate::config::*;
+
+#[cfg(feature = "blocking")]
+use reqwest::blocking::RequestBuilder;
+
+#[cfg(feature = "async")]
+use reqwest::RequestBuilder;
+
+const MMD_NAMESPACE: &str = "http://musicbrainz.org/ns/mmd-2.0#";
+
+/// Errors that can occur while submitting data to MusicBrainz.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SubmissionError {
+    /// The ISRC is not made of a 2 letters country code, a 3 characters registrant code
+    /// and a 7 digits designation code (ex: `USSM19902990`).
+    InvalidIsrc(String),
+    /// The barcode is not a 8 (EAN-8), 12 (UPC-A), 13 (EAN-13) or 14 (GTIN-14) digits code.
+    InvalidBarcode(String),
+    /// MusicBrainz refused the submission, `message` holds the reason it gave.
+    Rejected { status: u16, message: String },
+    /// The request could not be sent, or its response could not be read.
+    Http(crate::Error),
+}
+
+impl fmt::Display for SubmissionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmissionError::InvalidIsrc(isrc) => write!(f, "invalid ISRC: {:?}", isrc),
+            SubmissionError::InvalidBarcode(barcode) => {
+                write!(f, "invalid barcode: {:?}", barcode)
+            }
+            SubmissionError::Rejected { status, message } => {
+                write!(f, "submission rejected ({}): {}", status, message)
+            }
+            SubmissionError::Http(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for SubmissionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SubmissionError::Http(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<crate::Error> for SubmissionError {
+    fn from(err: crate::Error) -> Self {
+        SubmissionError::Http(err)
+    }
+}
+
+impl From<reqwest::Error> for SubmissionError {
+    fn from(err: reqwest::Error) -> Self {
+        SubmissionError::Http(err.into())
+    }
+}
+
+/// The answer MusicBrainz sends back for an accepted submission.
+///
+/// Note that the web service does not report the ids of the edits it opened, only a short
+/// status message (usually `"OK"`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmissionResponse {
+    pub message: String,
+}
+
+/// Check that `isrc` is a 12 characters [ISRC](https://musicbrainz.org/doc/ISRC), without
+/// hyphens.
+pub fn is_valid_isrc(isrc: &str) -> bool {
+    let bytes = isrc.as_bytes();
+    bytes.len() == 12
+        && bytes[..2].iter().all(u8::is_ascii_uppercase)
+        && bytes[2..5]
+            .iter()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+        && bytes[5..].iter().all(u8::is_ascii_digit)
+}
+
+/// Check that `barcode` is a [UPC or EAN](https://musicbrainz.org/doc/Barcode) code.
+pub fn is_valid_barcode(barcode: &str) -> bool {
+    matches!(barcode.len(), 8 | 12 | 13 | 14) && barcode.bytes().all(|c| c.is_ascii_digit())
+}
+
+pub(crate) fn isrc_submission_body(mbid: &str, isrcs: &[&str]) -> Result<String, SubmissionError> {
+    if let Some(invalid) = isrcs.iter().find(|isrc| !is_valid_isrc(isrc)) {
+        return Err(SubmissionError::InvalidIsrc(invalid.to_string()));
+    }
+
+    let isrc_list: String = isrcs
+        .iter()
+        .map(|isrc| format!("<isrc id=\"{}\"/>", isrc))
+        .collect();
+
+    Ok(format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+         <metadata xmlns=\"{}\">\
+         <recording-list><recording id=\"{}\">\
+         <isrc-list count=\"{}\">{}</isrc-list>\
+         </recording></recording-list>\
+         </metadata>",
+        MMD_NAMESPACE,
+        escape_xml(mbid),
+        isrcs.len(),
+        isrc_list
+    ))
+}
+
+pub(crate) fn barcode_submission_body(
+    mbid: &str,
+    barcode: &str,
+) -> Result<String, SubmissionError> {
+    if !is_valid_barcode(barcode) {
+        return Err(SubmissionError::InvalidBarcode(barcode.to_string()));
+    }
+
+    Ok(format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+         <metadata xmlns=\"{}\">\
+         <release-list><release id=\"{}\">\
+         <barcode>{}</barcode>\
+         </release></release-list>\
+         </metadata>",
+        MMD_NAMESPACE,
+        escape_xml(mbid),
+        barcode
+    ))
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Extract the `<text>` content of a web service xml message or error.
+fn message_text(body: &str) -> String {
+    body.split_once("<text>")
+        .and_then(|(_, rest)| rest.split_once("</text>"))
+        .map(|(text, _)| text.to_string())
+        .unwrap_or_else(|| body.trim().to_string())
+}
+
+fn submission_request(base_url: &str, entity: &str, body: String) -> RequestBuilder {
+    let client = SUBMISSION_CLIENT
+        .lock()
+        .expect("Unable to get musicbrainz submission client")
+        .clone();
+    let url = format!("{}/{}?client={}", base_url, entity, client);
+
+    let request = HTTP_CLIENT
+        .post(&url)
+        .header("Content-Type", "application/xml; charset=UTF-8")
+        .body(body);
+
+    with_auth_token(request)
+}
+
+fn submission_response(status: u16, body: &str) -> Result<SubmissionResponse, SubmissionError> {
+    let message = message_text(body);
+    if (200..300).contains(&status) {
+        Ok(SubmissionResponse { message })
+    } else {
+        Err(SubmissionError::Rejected { status, message })
+    }
+}
+
+#[cfg(feature = "blocking")]
+pub(crate) fn submit(
+    base_url: &str,
+    entity: &str,
+    body: String,
+) -> Result<SubmissionResponse, SubmissionError> {
+    let request = submission_request(base_url, entity, body);
+    let response = HTTP_CLIENT.send_once(request)?;
+    let status = response.status().as_u16();
+    submission_response(status, &response.text()?)
+}
+
+#[cfg(feature = "async")]
+pub(crate) async fn submit(
+    base_url: &str,
+    entity: &str,
+    body: String,
+) -> Result<SubmissionResponse, SubmissionError> {
+    let request = submission_request(base_url, entity, body);
+    let response = HTTP_CLIENT.send_once(request).await?;
+    let status = response.status().as_u16();
+    submission_response(status, &response.text().await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RECORDING_MBID: &str = "b9ad642e-b012-41c7-b72a-42cf4911f9ff";
+    const OK_RESPONSE: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+        <metadata xmlns=\"http://musicbrainz.org/ns/mmd-2.0#\">\
+        <message><text>OK</text></message></metadata>";
+    const UNAUTHORIZED_RESPONSE: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\
+        <error><text>You are not authorized to access this resource.</text></error>";
+
+    #[test]
+    fn should_validate_isrc() {
+        assert!(is_valid_isrc("USSM19902990"));
+        assert!(is_valid_isrc("GBAYE0601498"));
+        assert!(!is_valid_isrc("US-SM1-99-02990"));
+        assert!(!is_valid_isrc("ussm19902990"));
+        assert!(!is_valid_isrc("USSM1990299"));
+        assert!(!is_valid_isrc("USSM199029AB"));
+    }
+
+    #[test]
+    fn should_validate_barcode() {
+        assert!(is_valid_barcode("074646362822"));
+        assert!(is_valid_barcode("5099749534728"));
+        assert!(is_valid_barcode("12345670"));
+        assert!(!is_valid_barcode(""));
+        assert!(!is_valid_barcode("07464636282"));
+        assert!(!is_valid_barcode("07464636282X"));
+    }
+
+    #[test]
+    fn should_build_isrc_submission_body() {
+        let body = isrc_submission_body(RECORDING_MBID, &["USSM19902990", "GBAYE0601498"]).unwrap();
+
+        assert!(body.contains(&format!("<recording id=\"{}\">", RECORDING_MBID)));
+        assert!(body.contains(
+            "<isrc-list count=\"2\"><isrc id=\"USSM19902990\"/><isrc id=\"GBAYE0601498\"/></isrc-list>"
+        ));
+    }
+
+    #[test]
+    fn should_reject_invalid_isrc_before_submitting() {
+        let err = isrc_submission_body(RECORDING_MBID, &["USSM19902990", "nope"]).unwrap_err();
+
+        assert!(matches!(err, SubmissionError::InvalidIsrc(isrc) if isrc == "nope"));
+    }
+
+    #[test]
+    fn should_build_barcode_submission_body() {
+        let body = barcode_submission_body(RECORDING_MBID, "074646362822").unwrap();
+
+        assert!(body.contains("<barcode>074646362822</barcode>"));
+        assert!(matches!(
+            barcode_submission_body(RECORDING_MBID, "0746-4636"),
+            Err(SubmissionError::InvalidBarcode(_))
+        ));
+    }
+
+    #[test]
+    fn should_read_rejection_message() {
+        let err = submission_response(401, UNAUTHORIZED_RESPONSE).unwrap_err();
+
+        assert!(matches!(
+            err,
+            SubmissionError::Rejected { status: 401, message }
+                if message == "You are not authorized to access this resource."
+        ));
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn should_post_isrcs_to_submission_endpoint() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/recording")
+            .match_query(mockito::Matcher::Regex("client=".to_string()))
+            .match_header("content-type", "application/xml; charset=UTF-8")
+            .match_body(mockito::Matcher::Regex(
+                "<isrc id=\"USSM19902990\"/>".to_string(),
+            ))
+            .with_body(OK_RESPONSE)
+            .create();
+
+        let body = isrc_submission_body(RECORDING_MBID, &["USSM19902990"]).unwrap();
+        let response = submit(&server.url(), "recording", body).unwrap();
+
+        mock.assert();
+        assert_eq!(response.message, "OK");
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_post_isrcs_to_submission_endpoint() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/recording")
+            .match_query(mockito::Matcher::Regex("client=".to_string()))
+            .match_header("content-type", "application/xml; charset=UTF-8")
+            .match_body(mockito::Matcher::Regex(
+                "<isrc id=\"USSM19902990\"/>".to_string(),
+            ))
+            .with_body(OK_RESPONSE)
+            .create_async()
+            .await;
+
+        let body = isrc_submission_body(RECORDING_MBID, &["USSM19902990"]).unwrap();
+        let response = submit(&server.url(), "recording", body).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(response.message, "OK");
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn should_return_rejected_submission() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/recording")
+            .match_query(mockito::Matcher::Any)
+            .with_status(401)
+            .with_body(UNAUTHORIZED_RESPONSE)
+            .create();
+
+        let body = isrc_submission_body(RECORDING_MBID, &["USSM19902990"]).unwrap();
+        let err = submit(&server.url(), "recording", body).unwrap_err();
+
+        mock.assert();
+        assert!(matches!(
+            err,
+            SubmissionError::Rejected { status: 401, message }
+                if message == "You are not authorized to access this resource."
+        ));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_return_rejected_submission() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/recording")
+            .match_query(mockito::Matcher::Any)
+            .with_status(401)
+            .with_body(UNAUTHORIZED_RESPONSE)
+            .create_async()
+            .await;
+
+        let body = isrc_submission_body(RECORDING_MBID, &["USSM19902990"]).unwrap();
+        let err = submit(&server.url(), "recording", body).await.unwrap_err();
+
+        mock.assert_async().await;
+        assert!(matches!(
+            err,
+            SubmissionError::Rejected { status: 401, message }
+                if message == "You are not authorized to access this resource."
+        ));
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn should_not_retry_rate_limited_submission() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/recording")
+            .match_query(mockito::Matcher::Any)
+            .with_status(503)
+            .with_header("retry-after", "0")
+            .expect(1)
+            .create();
+
+        let body = isrc_submission_body(RECORDING_MBID, &["USSM19902990"]).unwrap();
+        let err = submit(&server.url(), "recording", body).unwrap_err();
+
+        mock.assert();
+        assert!(matches!(
+            err,
+            SubmissionError::Http(crate::Error::RateLimited { .. })
+        ));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_not_retry_rate_limited_submission() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/recording")
+            .match_query(mockito::Matcher::Any)
+            .with_status(503)
+            .with_header("retry-after", "0")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let body = isrc_submission_body(RECORDING_MBID, &["USSM19902990"]).unwrap();
+        let err = submit(&server.url(), "recording", body).await.unwrap_err();
+
+        mock.assert_async().await;
+        assert!(matches!(
+            err,
+            SubmissionError::Http(crate::Error::RateLimited { .. })
+        ));
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn should_post_barcode_to_submission_endpoint() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/release")
+            .match_query(mockito::Matcher::Regex("client=".to_string()))
+            .match_body(mockito::Matcher::Regex(
+                "<barcode>074646362822</barcode>".to_string(),
+            ))
+            .with_body(OK_RESPONSE)
+            .create();
+
+        let body = barcode_submission_body(RECORDING_MBID, "074646362822").unwrap();
+        let response = submit(&server.url(), "release", body).unwrap();
+
+        mock.assert();
+        assert_eq!(response.message, "OK");
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn should_post_barcode_to_submission_endpoint() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/release")
+            .match_query(mockito::Matcher::Regex("client=".to_string()))
+            .match_body(mockito::Matcher::Regex(
+                "<barcode>074646362822</barcode>".to_string(),
+            ))
+            .with_body(OK_RESPONSE)
+            .create_async()
+            .await;
+
+        let body = barcode_submission_body(RECORDING_MBID, "074646362822").unwrap();
+        let response = submit(&server.url(), "release", body).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(response.message, "OK");
+    }
+}
@@ -31,15 +31,16 @@ async fn should_get_artist_by_id() {
     assert_eq!(
         nirvana.unwrap(),
         Artist {
-            id: String::from("5b11f4ce-a62d-471e-81fc-a69a8278c7da"),
+            id: "5b11f4ce-a62d-471e-81fc-a69a8278c7da".parse().unwrap(),
             name: String::from("Nirvana"),
             sort_name: String::from("Nirvana"),
             disambiguation: String::from("1980s~1990s US grunge band"),
             artist_type: Some(Group),
             gender: None,
+            gender_id: None,
             country: Some("US".to_string()),
             area: Some(Area {
-                id: "489ce91b-6658-3307-9877-795b68554c98".to_string(),
+                id: "489ce91b-6658-3307-9877-795b68554c98".parse().unwrap(),
                 area_type: None,
                 type_id: None,
                 disambiguation: "".to_string(),
@@ -54,6 +55,7 @@ async fn should_get_artist_by_id() {
                 annotation: None,
             }),
             begin_area: None,
+            end_area: None,
             life_span: Some(LifeSpan {
                 ended: Some(true),
                 begin: Some(NaiveDate::from_ymd_opt(1987, 1, 1).unwrap()),
@@ -67,6 +69,7 @@ async fn should_get_artist_by_id() {
             works: None,
             aliases: None,
             rating: None,
+            user_rating: None,
             genres: None,
             annotation: None,
         }
@@ -90,14 +93,16 @@ async fn should_get_artist_relations_from_release() {
             end: None,
             attributes: Some(vec![]),
             content: RelationContent::Artist(Box::new(Artist {
-                id: "0944a9f5-65be-44b6-9e8e-33732fdfe923".to_string(),
+                id: "0944a9f5-65be-44b6-9e8e-33732fdfe923".parse().unwrap(),
                 name: "Dave McDonald".to_string(),
                 sort_name: "McDonald, Dave".to_string(),
                 disambiguation: "sound engineer for Portishead".to_string(),
                 artist_type: Some(Person),
                 gender: None,
+                gender_id: None,
                 area: None,
                 begin_area: None,
+                end_area: None,
                 relations: None,
                 releases: None,
                 works: None,
@@ -107,6 +112,7 @@ async fn should_get_artist_relations_from_release() {
                 tags: None,
                 genres: None,
                 rating: None,
+                user_rating: None,
                 country: None,
                 annotation: None,
                 life_span: None
@@ -120,7 +126,8 @@ async fn should_get_artist_relations_from_release() {
             type_id: "87e922ba-872e-418a-9f41-0a63aa3c30cc".to_string(),
             begin: None,
             direction: "backward".to_string(),
-            relation_type: "engineer".to_string()
+            relation_type: "engineer".to_string(),
+            ordering_key: None
         }]
     );
 }
@@ -135,7 +142,7 @@ async fn should_get_recording_by_id() {
     assert_eq!(
         polly.unwrap(),
         Recording {
-            id: "af40d6b8-58e8-4ca5-9db8-d4fca0b899e2".to_string(),
+            id: "af40d6b8-58e8-4ca5-9db8-d4fca0b899e2".parse().unwrap(),
             title: "(New Wave) Polly".to_string(),
             video: Some(false),
             length: Some(246_000),
@@ -144,8 +151,10 @@ async fn should_get_recording_by_id() {
             artist_credit: None,
             relations: None,
             releases: None,
+            first_release_date: Some(NaiveDate::from_ymd_opt(1992, 12, 14).unwrap()),
             tags: None,
             rating: None,
+            user_rating: None,
             genres: None,
             annotation: None,
             isrcs: None,
@@ -163,7 +172,7 @@ async fn should_get_release_group_by_id() {
     assert_eq!(
         in_utero.unwrap(),
         ReleaseGroup {
-            id: "2a0981fb-9593-3019-864b-ce934d97a16e".to_string(),
+            id: "2a0981fb-9593-3019-864b-ce934d97a16e".parse().unwrap(),
             primary_type_id: Some("f529b476-6e62-324f-b0aa-1f3e33d313fc".to_string()),
             primary_type: Some(ReleaseGroupPrimaryType::Album),
             secondary_type_ids: vec![],
@@ -177,6 +186,7 @@ async fn should_get_release_group_by_id() {
             tags: None,
             aliases: None,
             rating: None,
+            user_rating: None,
             genres: None,
             annotation: None,
         }
@@ -193,17 +203,39 @@ async fn should_get_release() {
     assert_eq!(
         in_utero.unwrap(),
         Release {
-            id: "18d4e9b4-9247-4b44-914a-8ddec3502103".to_string(),
+            id: "18d4e9b4-9247-4b44-914a-8ddec3502103".parse().unwrap(),
             title: "In Utero".to_string(),
             status_id: Some("4e304316-386d-3409-af2e-78857eec5cfe".to_string()),
             status: Some(ReleaseStatus::Official),
             date: Some(NaiveDate::from_ymd_opt(1993, 1, 1).unwrap()),
             country: Some("US".to_string()),
+            release_events: Some(vec![ReleaseEvent {
+                date: Some(NaiveDate::from_ymd_opt(1993, 1, 1).unwrap()),
+                area: Some(Area {
+                    id: "489ce91b-6658-3307-9877-795b68554c98".parse().unwrap(),
+                    area_type: None,
+                    type_id: None,
+                    disambiguation: "".to_string(),
+                    name: "United States".to_string(),
+                    sort_name: "United States".to_string(),
+                    relations: None,
+                    iso_3166_1_codes: Some(vec!["US".to_string()]),
+                    life_span: None,
+                    tags: None,
+                    aliases: None,
+                    genres: None,
+                    annotation: None,
+                }),
+            }]),
             quality: Some(ReleaseQuality::Normal),
             barcode: Some("0208314671259".to_string()),
             disambiguation: Some("".to_string()),
             packaging_id: Some("ec27701a-4a22-37f4-bfac-6616e0f9750a".to_string()),
             packaging: Some(ReleasePackaging::JewelCase),
+            text_representation: Some(ReleaseTextRepresentation {
+                language: Some(Language::Eng),
+                script: Some(ReleaseScript::Latn),
+            }),
             relations: None,
             artist_credit: None,
             label_info: None,
@@ -227,12 +259,12 @@ async fn should_get_work_by_id() {
     assert_eq!(
         hotel_california.unwrap(),
         Work {
-            id: "22457dc0-ecbf-38f5-9056-11c858530a50".to_string(),
+            id: "22457dc0-ecbf-38f5-9056-11c858530a50".parse().unwrap(),
             title: "Hotel California".to_string(),
             type_id: Some("f061270a-2fd6-32f1-a641-f0f8676d14e6".to_string()),
             work_type: Some(WorkType::Song),
-            languages: Some(vec!["eng".to_string()]),
-            language: Some("eng".to_string()),
+            languages: Some(vec![Language::Eng]),
+            language: Some(Language::Eng),
             iswcs: Some(vec![
                 "T-070.076.790-3".to_string(),
                 "T-900.316.249-0".to_string(),
@@ -270,6 +302,7 @@ async fn should_get_work_by_id() {
             tags: None,
             aliases: None,
             rating: None,
+            user_rating: None,
             genres: None,
             annotation: None,
         }
@@ -286,7 +319,7 @@ async fn should_get_label_by_id() {
     assert_eq!(
         ninja_tune.unwrap(),
         Label {
-            id: "dc940013-b8a8-4362-a465-291026c04b42".to_string(),
+            id: "dc940013-b8a8-4362-a465-291026c04b42".parse().unwrap(),
             type_id: Some("7aaa37fe-2def-3476-b359-80245850062d".to_string()),
             label_type: Some(LabelType::OriginalProduction),
             name: "Ninja Tune".to_string(),
@@ -299,6 +332,7 @@ async fn should_get_label_by_id() {
             aliases: None,
             tags: None,
             rating: None,
+            user_rating: None,
             genres: None,
             annotation: None,
         }
@@ -315,7 +349,7 @@ async fn should_get_area_by_id() {
     assert_eq!(
         aberdeen.unwrap(),
         Area {
-            id: "a640b45c-c173-49b1-8030-973603e895b5".to_string(),
+            id: "a640b45c-c173-49b1-8030-973603e895b5".parse().unwrap(),
             area_type: Some(City),
             type_id: Some("6fd8f29a-3d0a-32fc-980d-ea697b69da78".to_string()),
             disambiguation: "".to_string(),
@@ -346,7 +380,7 @@ async fn should_get_event_by_id() {
     assert_eq!(
         dour_festival_1989.unwrap(),
         Event {
-            id: "73df2f48-383b-4930-bad3-05ba938be578".to_string(),
+            id: "73df2f48-383b-4930-bad3-05ba938be578".parse().unwrap(),
             name: "Dour 1989".to_string(),
             cancelled: Some(false),
             type_id: Some("b6ded574-b592-3f0e-b56e-5b5f06aa0678".to_string()),
@@ -363,6 +397,7 @@ async fn should_get_event_by_id() {
             tags: None,
             aliases: None,
             rating: None,
+            user_rating: None,
             genres: None,
             annotation: None,
         }
@@ -379,7 +414,7 @@ async fn should_get_instrument() {
     assert_eq!(
         mandoline.unwrap(),
         Instrument {
-            id: "37fa9bb5-d5d7-4b0f-aa4d-531339ba9c32".to_string(),
+            id: "37fa9bb5-d5d7-4b0f-aa4d-531339ba9c32".parse().unwrap(),
             name: "mandolin".to_string(),
             instrument_type: StringInstrument,
             type_id: "cc00f97f-cf3d-3ae2-9163-041cb1a0d726".to_string(),
@@ -404,7 +439,7 @@ async fn should_get_place() {
     assert_eq!(
         blue_note_record.unwrap(),
         Place {
-            id: "327c29c6-da63-4dc9-a117-1917ee691ce4".to_string(),
+            id: "327c29c6-da63-4dc9-a117-1917ee691ce4".parse().unwrap(),
             name: "Blue Note".to_string(),
             disambiguation: Some("Chicago, 1954-1960".to_string()),
             life_span: Some(LifeSpan {
@@ -416,7 +451,7 @@ async fn should_get_place() {
             place_type: Some(Venue),
             address: Some("3 North Clark Street, Chicago, IL 60602".to_string()),
             area: Some(Area {
-                id: "29a709d8-0320-493e-8d0c-f2c386662b7f".to_string(),
+                id: "29a709d8-0320-493e-8d0c-f2c386662b7f".parse().unwrap(),
                 disambiguation: "".to_string(),
                 sort_name: "Chicago".to_string(),
                 name: "Chicago".to_string(),
@@ -453,7 +488,7 @@ async fn should_get_series() {
     assert_eq!(
         la_chanson_du_dimanche.unwrap(),
         Series {
-            id: "814fb4d5-327f-4e37-8784-f8a707e5f97c".to_string(),
+            id: "814fb4d5-327f-4e37-8784-f8a707e5f97c".parse().unwrap(),
             type_id: "dd968243-7128-30a2-81f0-79843430a8e2".to_string(),
             series_type: SeriesType::RecordingSeries,
             disambiguation: "".to_string(),
@@ -478,7 +513,7 @@ async fn should_get_url() {
         svinkels_dot_com.unwrap(),
         Url {
             resource: "http://www.svinkels.com/".to_string(),
-            id: "9237f6da-fec6-4b8a-9d52-c7c18e0e2630".to_string(),
+            id: "9237f6da-fec6-4b8a-9d52-c7c18e0e2630".parse().unwrap(),
             tags: None,
         }
     );
@@ -255,3 +255,17 @@ async fn should_get_artist_annotation() {
 
     assert!(franz_joseph_haydn.annotation.is_some());
 }
+
+#[tokio::test]
+async fn should_fetch_artist_begin_area() {
+    let nirvana = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute()
+        .await
+        .unwrap();
+
+    let begin_area = nirvana.fetch_begin_area().await.unwrap().unwrap();
+
+    assert_eq!(begin_area.name, "Aberdeen");
+    assert!(begin_area.area_type.is_some());
+}
@@ -1,2 +1,3 @@
 mod place_browse;
 mod place_includes;
+mod place_search;
@@ -0,0 +1,24 @@
+use musicbrainz_rs_nova::entity::place::*;
+use musicbrainz_rs_nova::Search;
+
+#[tokio::test]
+async fn should_search_place_in_bounding_box() {
+    let london = BoundingBox::around(51.5074, -0.1278, 15.0);
+    let query = PlaceSearchQuery::query_builder()
+        .place("Abbey Road Studios")
+        .and()
+        .bounding_box(&london)
+        .build();
+
+    let result = Place::search(query).execute().await.unwrap();
+
+    assert!(result
+        .entities
+        .iter()
+        .any(|place| place.id == "bea135c0-a32e-49be-85fd-9234c73fa0a8"));
+    assert!(result
+        .entities
+        .iter()
+        .filter_map(|place| place.coordinates.as_ref())
+        .all(|coordinates| london.contains(coordinates)));
+}
@@ -238,3 +238,16 @@ fn should_get_artist_annotation() {
 
     assert!(franz_joseph_haydn.annotation.is_some());
 }
+
+#[test]
+fn should_fetch_artist_begin_area() {
+    let nirvana = Artist::fetch()
+        .id("5b11f4ce-a62d-471e-81fc-a69a8278c7da")
+        .execute()
+        .unwrap();
+
+    let begin_area = nirvana.fetch_begin_area().unwrap().unwrap();
+
+    assert_eq!(begin_area.name, "Aberdeen");
+    assert!(begin_area.area_type.is_some());
+}
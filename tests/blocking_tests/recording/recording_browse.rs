@@ -45,3 +45,18 @@ fn should_browse_recording_by_release() {
     assert_eq!(recording_on_hooker_n_heat.offset, 0);
     assert!(!recording_on_hooker_n_heat.entities.is_empty());
 }
+
+#[test]
+fn should_browse_audio_recording_by_artist() {
+    let audio_recordings_by_svinkels = Recording::browse()
+        .by_artist("770d490e-c89b-4775-8508-aca7c75142cd")
+        .execute()
+        .unwrap()
+        .exclude_videos();
+
+    assert!(!audio_recordings_by_svinkels.entities.is_empty());
+    assert!(audio_recordings_by_svinkels
+        .entities
+        .iter()
+        .all(|recording| !recording.is_video()));
+}
@@ -141,3 +141,18 @@ fn should_get_recording_level_relations() {
         .iter()
         .any(|rel| rel.target_type.as_ref().unwrap() == "work"));
 }
+
+#[test]
+fn should_get_recording_artist_credits() {
+    let association_de_gens_normal = Recording::fetch()
+        .id("f5f10cee-5d84-41d0-805d-3503872c151d")
+        .with_artist_credits()
+        .execute();
+
+    let artist_credit = association_de_gens_normal.unwrap().artist_credit.unwrap();
+
+    assert!(artist_credit.iter().any(|credit| credit.name == "TTC"));
+    assert!(artist_credit
+        .iter()
+        .any(|credit| credit.joinphrase.is_some()));
+}
@@ -1,5 +1,5 @@
-use musicbrainz_rs_nova::entity::release::Media;
 use musicbrainz_rs_nova::entity::release::Release;
+use musicbrainz_rs_nova::entity::release::{Media, MediaFormat};
 use musicbrainz_rs_nova::prelude::*;
 
 use std::collections::HashSet;
@@ -27,7 +27,7 @@ fn should_get_release_media() {
         .media
         .unwrap()
         .iter()
-        .any(|media| media.format.as_ref().unwrap() == "CD"));
+        .any(|media| media.format == Some(MediaFormat::CD)));
 }
 
 #[test]
@@ -177,3 +177,17 @@ fn should_get_release_annotation() {
 
     assert!(pieds_nus_sur_la_braise.annotation.is_some());
 }
+
+#[test]
+fn should_get_release_discids() {
+    let in_utero = Release::fetch()
+        .id("76df3287-6cda-33eb-8e9a-044b5e15ffdd")
+        .with_discids()
+        .execute()
+        .unwrap();
+
+    let medias: Vec<Media> = in_utero.media.unwrap();
+
+    assert!(medias.iter().all(|media| media.has_discids()));
+    assert!(medias.iter().all(|media| media.disc_count_actual() > 0));
+}